@@ -1,3 +1,14 @@
+// Requests made with a session cookie have to carry its CSRF token
+let session = null;
+async function post(url, options = {}) {
+  if(session === null) {
+    session = fetch("/auth/session").then(resp => resp.ok ? resp.json() : {}).catch(() => ({}));
+  }
+  const token = (await session).csrf_token;
+  const headers = token ? {'X-CSRF-Token': token} : {};
+  return fetch(url, {...options, method: 'POST', headers});
+}
+
 async function fetchTasks() {
   const tasks = await (await fetch("/api/v1/tasks")).json()
   let table = document.getElementById('tasks');
@@ -19,6 +30,28 @@ const TaskList = Vue.extend({
   },
 
   methods: {
+    async refreshTasks() {
+      let tasks = await (await fetch("/api/v1/tasks")).json()
+      let categories = {};
+      for(let task of Object.keys(tasks).sort()) {
+        this.$set(this.tasks, task, tasks[task])
+        let category = tasks[task].meta?.category || '';
+        categories[category] = categories[category] || [];
+        categories[category].push(task);
+      }
+
+      for(let task in this.tasks) {
+        if(!tasks[task]) {
+          this.$delete(this.tasks, task)
+        }
+      }
+      this.task_categories = categories;
+      await this.$nextTick();
+      for(let task of this.$refs.tasks || []) {
+        task.updateArgs();
+      }
+    },
+
     initSse() {
       this.sseOpened = false;
       this.eventSource = new EventSource('/api/v1/events', { withCredentials: true });
@@ -39,34 +72,22 @@ const TaskList = Vue.extend({
         task.state = 'finished'
         task.exit_code = data.exit_code
         if(!this.$root.$data.task_outputs.hasOwnProperty(data.task)) return;
-        let resp = await fetch(`/api/v1/task/${data.task}/output`);
-        let text = await resp.text();
-        this.$set(this.$root.$data.task_outputs, data.task, text.trim().split("\n"));
-        for(let task of this.$refs.tasks) {
-          for(let arg of task.task.arguments) {
-            if(arg.enum_source == data.task) {
-              task.updateArgs();
-            }
-          }
-        }
+        await this.refreshTasks();
       })
 
-      this.eventSource.addEventListener('update_config', async () => {
-        let tasks = await (await fetch("/api/v1/tasks")).json()
-        let categories = {};
-        for(let task of Object.keys(tasks).sort()) {
-          this.$set(this.tasks, task, tasks[task])
-          let category = tasks[task].meta?.category || '';
-          categories[category] = categories[category] || [];
-          categories[category].push(task);
-        }
+      this.eventSource.addEventListener('approval_requested', (e) => {
+        let data = JSON.parse(e.data)
+        this.tasks[data.task].pending_approval = data.approval
+      })
 
-        for(let task in this.tasks) {
-          if(!tasks[task]) {
-            this.$delete(this.tasks, task)
-          }
-        }
-        this.task_categories = categories;
+      this.eventSource.addEventListener('approval_resolved', (e) => {
+        let data = JSON.parse(e.data)
+        let task = this.tasks[data.task]
+        if(task.pending_approval?.id == data.id) task.pending_approval = null
+      })
+
+      this.eventSource.addEventListener('update_config', async () => {
+        await this.refreshTasks();
       })
 
       this.eventSource.addEventListener('change_data', () => {
@@ -123,7 +144,8 @@ Vue.component('task', {
   data() {
     return {
       args: {},
-      arg_values: {},
+      dynamic_options: {},
+      options_keys: {},
       output_shown: false,
       since: null,
       interval: null,
@@ -135,50 +157,121 @@ Vue.component('task', {
       this.output_shown = true
     }
     for(let arg of this.task.arguments) {
+      const fallback = this.data_value(arg) ?? arg.default;
+      if(arg.multiple) {
+        this.$set(this.args, arg.name, fallback !== null ? [fallback] : []);
+      } else if(fallback !== null) {
+        this.$set(this.args, arg.name, arg.datatype == 'Bool' ? fallback == 'true' : fallback);
+      } else if(arg.datatype == 'Bool') {
+        this.$set(this.args, arg.name, false);
+      }
+
+      if(arg.datatype != 'Enum' || !arg.enum_source || arg.depends_on.length || this.task.options[arg.name]) continue;
+
+      // The source hasn't been run yet, so run it, and refresh the choices once it's done
       if(this.$root.$data.task_outputs[arg.enum_source] === undefined) {
         const promise = new Promise(async (resolve, reject) => {
-          resp = await fetch(`/api/v1/task/${arg.enum_source}/output`, {method: 'POST'});
+          resp = await post(`/api/v1/task/${arg.enum_source}/output`);
           if(!resp.ok) return reject();
-          let data = await resp.text();
-          data = data.trim().split("\n");
-          this.$set(this.$root.$data.task_outputs, arg.enum_source, data);
-          resolve(data);
+          resolve(await resp.text());
         });
         this.$root.$data.task_outputs[arg.enum_source] = promise;
+        promise.then(() => this.$parent.refreshTasks());
       }
-      const data = await this.$root.$data.task_outputs[arg.enum_source];
-      this.$set(this.args, arg.name, data[0]);
-      this.arg_values[arg.enum_source] = data;
     }
+    this.updateArgs();
+    this.$watch('args', () => this.loadDependentOptions(), {deep: true});
+    this.loadDependentOptions();
   },
 
   methods: {
     updateArgs() {
       for(let arg of this.task.arguments) {
-        const data = this.$root.$data.task_outputs[arg.enum_source];
+        if(arg.datatype != 'Enum') continue;
+        const data = this.options_for(arg).map(option => option.value);
         const val = this.args[arg.name];
         // The select doesn't seem to update properly when this value isn't actually changed.
         // So we make sure it changes.
         this.$set(this.args, arg.name, null);
-        if(data.includes(val)) {
+        if(arg.multiple) {
+          this.$set(this.args, arg.name, (val || []).filter(v => data.includes(v)));
+        } else if(data.includes(val)) {
           this.$set(this.args, arg.name, val);
-        } else {
+        } else if(arg.required && data.length) {
           this.$set(this.args, arg.name, data[0]);
         }
-        this.arg_values[arg.enum_source] = data;
       }
     },
 
+    // The value of another task's data the argument is sourced from, if it's visible
+    data_value(arg) {
+      if(!arg.data_source) return null;
+      return this.$root.$data.tasks[arg.data_source.task]?.data?.[arg.data_source.name] ?? null;
+    },
+
+    options_for(arg) {
+      return this.dynamic_options[arg.name] || this.task.options[arg.name] || [];
+    },
+
+    // Choices of arguments depending on other arguments have to be asked for every time those change
+    async loadDependentOptions() {
+      for(let arg of this.task.arguments) {
+        if(!arg.depends_on.length) continue;
+        const key = JSON.stringify(arg.depends_on.map(dep => this.args[dep]));
+        if(this.options_keys[arg.name] === key) continue;
+        this.options_keys[arg.name] = key;
+
+        let params = new URLSearchParams("");
+        for(let name in this.args) {
+          if(name == arg.name || this.args[name] === null || this.args[name] === undefined) continue;
+          for(let value of [].concat(this.args[name])) {
+            params.append(name, value);
+          }
+        }
+        const resp = await fetch(`/api/v1/task/${this.name}/arguments/${arg.name}/options?${params}`);
+        this.$set(this.dynamic_options, arg.name, resp.ok ? await resp.json() : []);
+        this.updateArgs();
+      }
+    },
+
+    format_value(value) {
+      return Array.isArray(value) ? value.join(', ') : value;
+    },
+
+    input_type(arg) {
+      if(arg.sensitive && arg.datatype == 'String') return 'password';
+      return {Int: 'number', Float: 'number', Date: 'date', DateTime: 'datetime-local'}[arg.datatype] || 'text';
+    },
+
     run() {
-      let params = new URLSearchParams("");
+      // Files can only be sent in a multipart body
+      const files = this.task.arguments.some(arg => arg.datatype == 'File');
+      let params = files ? new FormData() : new URLSearchParams("");
       for(let arg in this.args) {
-        params.append(arg, this.args[arg]);
+        if(this.args[arg] === null || this.args[arg] === undefined) continue;
+        for(let value of [].concat(this.args[arg])) {
+          params.append(arg, value);
+        }
+      }
+      if(files) {
+        post(`/api/v1/task/${this.name}`, {body: params})
+      } else {
+        post(`/api/v1/task/${this.name}?${params}`)
       }
-      fetch(`/api/v1/task/${this.name}?${params}`, {method: 'POST'})
     },
 
     stop() {
-      fetch(`/api/v1/task/${this.name}/stop`, {method: 'POST'})
+      post(`/api/v1/task/${this.name}/stop`)
+    },
+
+    async approve() {
+      const resp = await post(`/api/v1/task/${this.name}/approve?id=${this.task.pending_approval.id}`);
+      if(!resp.ok) alert(await resp.text());
+    },
+
+    async reject() {
+      const resp = await post(`/api/v1/task/${this.name}/reject?id=${this.task.pending_approval.id}`);
+      if(!resp.ok) alert(await resp.text());
     },
 
     show_output() {
@@ -1,24 +1,42 @@
 use parking_lot::RwLock;
 use std::sync::Arc;
 use std::collections::HashMap;
+use std::time::Instant;
 use tokio::sync::broadcast;
 
-use crate::cfg::Config;
-use crate::task::TaskState;
+use crate::cfg::{Config, Token};
+use crate::tokens;
+use crate::task::{TaskState, Resolution};
 use crate::event::{Event, send_message};
+use crate::audit::{AuditLog, Record, Outcome};
 
 #[derive(Clone)]
 pub struct AppState {
     config_path: String,
     pub config: Config,
     pub tasks: HashMap<String, Arc<RwLock<TaskState>>>,
-    pub events: tokio::sync::broadcast::Sender<Event>
+    pub events: tokio::sync::broadcast::Sender<Event>,
+    /// Outputs of enum sources run with arguments, and when they were run.
+    pub enum_cache: HashMap<EnumCacheKey, (Instant, Vec<u8>)>,
+    /// Verdicts of argument validators, and when they were given.
+    pub validator_cache: HashMap<ValidatorCacheKey, (Instant, Result<(), String>)>,
+    /// Tokens created with the API, read from the token file.
+    pub tokens: Vec<Token>,
+    pub audit: Arc<AuditLog>,
 }
 
+/// The name of a task, the arguments it has been run with, and the user it has been run for.
+pub type EnumCacheKey = (String, Vec<(String, Vec<String>)>, Option<String>);
+
+/// The task and argument the validator belongs to, its command, the value it has checked, and the user who has given it.
+pub type ValidatorCacheKey = (String, String, Vec<String>, String, Option<String>);
+
 impl AppState {
     pub fn new(config_path: impl Into<String>) -> Arc<RwLock<AppState>> {
         let config_path = config_path.into();
-        let config = Config::read(&config_path); 
+        let config = Config::read(&config_path).unwrap_or_else(|e| panic!("{:#}", e));
+        let tokens = tokens::read(&config).unwrap_or_else(|e| panic!("{:#}", e));
+        let audit = AuditLog::new(config.audit.as_ref()).unwrap_or_else(|e| panic!("{:#}", e));
         let mut task_states = HashMap::new();
         let task_names: Vec<String> = config.tasks.keys().map(String::from).collect();
         for name in &task_names {
@@ -30,19 +48,54 @@ impl AppState {
             config: config.clone(),
             tasks: task_states,
             events: broadcast::channel(16).0,
+            enum_cache: HashMap::new(),
+            validator_cache: HashMap::new(),
+            tokens,
+            audit: Arc::new(audit),
         }))
     }
 }
 
 pub fn reload_config(app_state: &Arc<RwLock<AppState>>) {
     let old_config = app_state.read().config.clone();
-    let new_config = Config::read(&app_state.read().config_path);
+    let audit = app_state.read().audit.clone();
+    let read = Config::read(&app_state.read().config_path).and_then(|config| {
+        let tokens = tokens::read(&config)?;
+        audit.configure(config.audit.as_ref())?;
+        Ok((config, tokens))
+    });
+    let (new_config, tokens) = match read {
+        Ok(read) => read,
+        Err(e) => {
+            eprintln!("Not reloading the configuration: {:#}", e);
+            audit.write(&Record::new("reload", Outcome::Failed).detail(format!("{:#}", e)));
+            return
+        }
+    };
     app_state.write().config = new_config.clone();
+    app_state.write().tokens = tokens;
+    app_state.write().enum_cache.clear();
+    app_state.write().validator_cache.clear();
 
     for task in new_config.tasks.keys() {
         if !old_config.tasks.contains_key(task) {
             app_state.write().tasks.insert(task.to_owned(), Arc::new(RwLock::new(TaskState::new(task))));
         }
     }
+
+    // Runs waiting for approval have been prepared with the old configuration, so they can't start if it has changed
+    let state = app_state.read();
+    for (name, task_state) in &state.tasks {
+        if new_config.tasks.get(name) == old_config.tasks.get(name) {
+            continue
+        }
+        let pending = task_state.write().pending.take();
+        if let Some(pending) = pending {
+            audit.write(&Record::new("approval_expired", Outcome::Success).user(pending.approval.requested_by).task(name).detail(pending.approval.id.clone()));
+            send_message(&state.events, Event::ApprovalResolved(name.clone(), pending.approval.id, Resolution::Expired, None));
+        }
+    }
+    drop(state);
+    audit.write(&Record::new("reload", Outcome::Success));
     send_message(&app_state.read().events, Event::UpdateConfig);
 }
@@ -0,0 +1,428 @@
+use std::path::{Path, PathBuf, Component};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use actix_web::HttpResponse;
+use futures::future::{FutureExt, LocalBoxFuture};
+use parking_lot::RwLock;
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use thiserror::Error;
+
+use crate::app_state::AppState;
+use crate::cfg::{Argument, ArgumentType, Choice, EnumFormat, Restrictions, TaskConfig, Validator};
+use crate::cmdline::Variable;
+use crate::task::{Invocation, capture, check};
+
+/// How long an `enum_source` run with the values of other arguments can take.
+const ENUM_SOURCE_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Error)]
+pub enum ArgumentError {
+    /// The value sent by the client is wrong.
+    #[error("{0}")]
+    Invalid(String),
+    /// The argument can't be validated because of its configuration.
+    #[error("{0}")]
+    Misconfigured(String),
+    /// The value is valid, but the user isn't allowed to use it.
+    #[error("{0}")]
+    Forbidden(String),
+}
+
+impl From<ArgumentError> for HttpResponse {
+    fn from(error: ArgumentError) -> Self {
+        match error {
+            ArgumentError::Invalid(_) => HttpResponse::BadRequest().body(error.to_string()),
+            ArgumentError::Misconfigured(_) => HttpResponse::InternalServerError().body(error.to_string()),
+            ArgumentError::Forbidden(_) => HttpResponse::Forbidden().body(error.to_string()),
+        }
+    }
+}
+
+/// What validation of a task's arguments needs to know besides the arguments themselves.
+pub struct Context<'a> {
+    pub state: &'a Arc<RwLock<AppState>>,
+    /// The task the arguments are given to.
+    pub task: String,
+    /// The user running the task, if any.
+    pub user: Option<String>,
+    /// Values of the arguments validated so far.
+    pub values: HashMap<String, Vec<String>>,
+    /// The restrictions the user has for the task.
+    pub restrictions: Restrictions,
+}
+
+impl<'a> Context<'a> {
+    pub fn new(state: &'a Arc<RwLock<AppState>>, task: impl Into<String>, user: Option<String>) -> Self {
+        Context { state, task: task.into(), user, values: HashMap::new(), restrictions: Restrictions::default() }
+    }
+}
+
+/// Applies the argument's default, and validates each of the given values.
+///
+/// Returns an empty list if an optional argument has no value.
+pub async fn resolve(arg: &Argument, given: Vec<String>, ctx: &Context<'_>) -> Result<Vec<String>, ArgumentError> {
+    let mut given: Vec<String> = if arg.multiple {
+        given.into_iter().filter(|value| !value.is_empty()).collect()
+    } else {
+        // If a single value is given more than once, the first one wins
+        given.into_iter().take(1).collect()
+    };
+
+    let data = data_value(arg, ctx)?;
+    let default = data.clone().or_else(|| arg.default.clone());
+    match given.first() {
+        Some(value) if !value.is_empty() => {},
+        // Whether an empty value is valid is up to the argument's datatype then
+        Some(_) if arg.required && default.is_none() => {},
+        // Otherwise, it means the same as no value at all
+        _ => given = default.into_iter().collect()
+    }
+    if given.is_empty() && arg.required {
+        return Err(ArgumentError::Invalid(format!("Missing argument {}", arg.name)));
+    }
+
+    // The data is compared in the same form as the values, e.g. `yes` is the same Bool as `true`
+    let data = match (data, arg.data_source.as_ref().filter(|source| source.restrict)) {
+        (Some(data), Some(_)) => Some(validate(arg, &data, ctx).await.unwrap_or(data)),
+        (data, _) => data
+    };
+    let mut values = Vec::with_capacity(given.len());
+    for value in given {
+        let value = validate(arg, &value, ctx).await?;
+        if let Some(source) = arg.data_source.as_ref().filter(|source| source.restrict) {
+            match data {
+                Some(ref data) if data == &value => {},
+                Some(_) => return Err(ArgumentError::Invalid(format!("Argument {} has to be the current {} of task {}.", arg.name, source.name, source.task))),
+                None => return Err(ArgumentError::Invalid(format!("Argument {} can't be given until {} of task {} is set.", arg.name, source.name, source.task)))
+            }
+        }
+        // Before the validator, which has no business seeing values the user isn't allowed to use
+        if !ctx.restrictions.allows(&arg.name, &value) {
+            return Err(ArgumentError::Forbidden(format!("You're not allowed to use {} as argument {}", value, arg.name)));
+        }
+        if let Some(ref validator) = arg.validator {
+            run_validator(arg, validator, &value, ctx).await?;
+        }
+        values.push(value);
+    }
+    Ok(values)
+}
+
+/// Returns the value of the task data an argument is sourced from, if it's set.
+fn data_value(arg: &Argument, ctx: &Context<'_>) -> Result<Option<String>, ArgumentError> {
+    let source = match arg.data_source {
+        Some(ref source) => source,
+        None => return Ok(None)
+    };
+    match ctx.state.read().tasks.get(&source.task) {
+        Some(task) => Ok(task.read().data.get(&source.name).cloned()),
+        None => Err(ArgumentError::Misconfigured(format!("Data source of argument {} doesn't exist, please fix its configuration.", arg.name)))
+    }
+}
+
+/// Asks an argument's validator command whether a value is acceptable.
+///
+/// The command gets the value on stdin, and in `TARU_VALUE`, along with `TARU_ARGUMENT` and `TARU_USER`.
+/// A non-zero exit code rejects the value, and the command's output is shown as the reason.
+async fn run_validator(arg: &Argument, validator: &Validator, value: &str, ctx: &Context<'_>) -> Result<(), ArgumentError> {
+    let key = (ctx.task.clone(), arg.name.clone(), validator.command.clone(), value.to_owned(), ctx.user.clone());
+    if let Some(ttl) = validator.cache_ttl {
+        if let Some((time, verdict)) = ctx.state.read().validator_cache.get(&key) {
+            if time.elapsed() <= Duration::from_secs(ttl) {
+                return verdict.clone().map_err(ArgumentError::Invalid);
+            }
+        }
+    }
+
+    let mut env = HashMap::new();
+    env.insert("TARU_ARGUMENT".to_owned(), arg.name.clone());
+    env.insert("TARU_VALUE".to_owned(), value.to_owned());
+    env.insert("TARU_USER".to_owned(), ctx.user.clone().unwrap_or_default());
+    let timeout = Duration::from_secs(validator.timeout);
+    let verdict = match check(&validator.command, &env, value.as_bytes(), timeout).await {
+        Ok(Some((Some(0), _))) => Ok(()),
+        Ok(Some((_, output))) => {
+            let message = String::from_utf8_lossy(&output).trim().to_owned();
+            Err(if message.is_empty() { format!("Argument {} has an invalid value.", arg.name) } else { message })
+        },
+        Ok(None) => return Err(ArgumentError::Misconfigured(format!("Validating argument {} took longer than {} seconds.", arg.name, validator.timeout))),
+        Err(e) => return Err(ArgumentError::Misconfigured(format!("Cannot run the validator of argument {}: {}", arg.name, e)))
+    };
+
+    if let Some(ttl) = validator.cache_ttl {
+        let mut state = ctx.state.write();
+        // Verdicts on other values expire too, so they're dropped instead of piling up
+        state.validator_cache.retain(|(task, name, ..), (time, _)| task != &ctx.task || name != &arg.name || time.elapsed() <= Duration::from_secs(ttl));
+        state.validator_cache.insert(key, (Instant::now(), verdict.clone()));
+    }
+    verdict.map_err(ArgumentError::Invalid)
+}
+
+/// Returns the arguments the choices of an argument depend on, directly or not.
+pub fn dependencies(task: &TaskConfig, arg: &Argument) -> HashSet<String> {
+    let mut dependencies = HashSet::new();
+    let mut queue = arg.depends_on.clone();
+    while let Some(name) = queue.pop() {
+        if dependencies.insert(name.clone()) {
+            if let Some(dependency) = task.arguments.iter().find(|arg| arg.name == name) {
+                queue.extend(dependency.depends_on.iter().cloned());
+            }
+        }
+    }
+    dependencies
+}
+
+/// Checks a value of an argument, and returns it in the form that should be passed to the command.
+pub async fn validate(arg: &Argument, value: &str, ctx: &Context<'_>) -> Result<String, ArgumentError> {
+    match arg.datatype {
+        ArgumentType::Int => {
+            match value.parse::<i32>() {
+                Ok(number) => check_range(arg, number as f64)?,
+                Err(_) => return Err(ArgumentError::Invalid(format!("Argument {} has to be a number, but is `{}` instead.", arg.name, value)))
+            }
+        },
+        ArgumentType::Float => {
+            match value.parse::<f64>() {
+                Ok(number) if number.is_finite() => check_range(arg, number)?,
+                _ => return Err(ArgumentError::Invalid(format!("Argument {} has to be a number, but is `{}` instead.", arg.name, value)))
+            }
+        },
+        ArgumentType::Bool => {
+            return match value.to_lowercase().as_str() {
+                "true" | "yes" | "on" | "1" => Ok("true".to_owned()),
+                "false" | "no" | "off" | "0" => Ok("false".to_owned()),
+                _ => Err(ArgumentError::Invalid(format!("Argument {} has to be true or false, but is `{}` instead.", arg.name, value)))
+            }
+        },
+        ArgumentType::String => {
+            if let Some(max_length) = arg.max_length {
+                if value.chars().count() > max_length {
+                    return Err(ArgumentError::Invalid(format!("Argument {} can be at most {} characters long.", arg.name, max_length)));
+                }
+            }
+            if let Some(ref pattern) = arg.pattern {
+                if !pattern.matches_whole(value) {
+                    return Err(ArgumentError::Invalid(format!("Argument {} has to match `{}`.", arg.name, pattern.as_str())));
+                }
+            }
+        },
+        ArgumentType::Path => return validate_path(arg, value),
+        // Uploads are checked while they're received
+        ArgumentType::File => {},
+        ArgumentType::Date => {
+            if NaiveDate::parse_from_str(value, "%Y-%m-%d").is_err() {
+                return Err(ArgumentError::Invalid(format!("Argument {} has to be a date (YYYY-MM-DD), but is `{}` instead.", arg.name, value)));
+            }
+        },
+        ArgumentType::DateTime => {
+            let valid = DateTime::parse_from_rfc3339(value).is_ok()
+                || NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S").is_ok()
+                || NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M").is_ok();
+            if !valid {
+                return Err(ArgumentError::Invalid(format!("Argument {} has to be a date and time (YYYY-MM-DDTHH:MM:SS), but is `{}` instead.", arg.name, value)));
+            }
+        },
+        ArgumentType::Enum => {
+            if value.is_empty() {
+                return Err(ArgumentError::Invalid(format!("Empty value for argument {}", arg.name)));
+            }
+            match choices(arg, ctx).await? {
+                Some(choices) => if !choices.iter().any(|choice| choice.value == value) {
+                    return Err(ArgumentError::Invalid(format!("Argument {} has an invalid value.", arg.name)));
+                },
+                None => return Err(ArgumentError::Invalid(format!("Data source of argument {} is not ready yet.", arg.name)))
+            }
+        },
+    }
+
+    Ok(value.to_owned())
+}
+
+fn parse_choices(arg: &Argument, data: &[u8]) -> Result<Vec<Choice>, ArgumentError> {
+    match arg.enum_format {
+        EnumFormat::Lines => Ok(data.split(|byte| *byte == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| String::from_utf8_lossy(line).into_owned())
+            .map(|value| Choice { label: value.clone(), value })
+            .collect()),
+        EnumFormat::Json => serde_json::from_slice(data).map_err(|e|
+            ArgumentError::Misconfigured(format!("Data source of argument {} is not a valid JSON list of choices: {}", arg.name, e))
+        ),
+    }
+}
+
+/// Returns the values an `Enum` accepts, or `None` if its source task hasn't finished yet.
+///
+/// This doesn't handle sources depending on other arguments, see `choices` for that.
+pub fn static_choices(arg: &Argument, state: &AppState) -> Result<Option<Vec<Choice>>, ArgumentError> {
+    if !arg.values.is_empty() {
+        Ok(Some(arg.values.clone()))
+    } else if let Some(ref enum_file) = arg.enum_file {
+        match std::fs::read(enum_file) {
+            Ok(data) => parse_choices(arg, &data).map(Some),
+            Err(e) => Err(ArgumentError::Misconfigured(format!("Cannot read the data source of argument {}: {}", arg.name, e)))
+        }
+    } else if let Some(ref enum_source) = arg.enum_source {
+        let source = match state.tasks.get(enum_source) {
+            Some(source) => source.read(),
+            None => return Err(ArgumentError::Misconfigured(format!("Data source of argument {} doesn't exist, please fix its configuration.", arg.name)))
+        };
+        if !source.status.is_finished() {
+            return Ok(None);
+        }
+        parse_choices(arg, &source.output).map(Some)
+    } else {
+        Err(ArgumentError::Misconfigured(format!("Argument {} is an enum without a data source, please fix its configuration.", arg.name)))
+    }
+}
+
+/// Returns the values an `Enum` accepts, given the values of the arguments it depends on.
+///
+/// If the source depends on other arguments, it's run with their values (unless it already has been).
+pub fn choices<'a>(arg: &'a Argument, ctx: &'a Context<'_>) -> LocalBoxFuture<'a, Result<Option<Vec<Choice>>, ArgumentError>> {
+    async move {
+        let source = match arg.enum_source {
+            Some(ref source) if !arg.depends_on.is_empty() => source,
+            _ => return static_choices(arg, &ctx.state.read())
+        };
+
+        let params: Vec<(String, Vec<String>)> = arg.depends_on.iter().map(|name|
+            (name.clone(), ctx.values.get(name).cloned().unwrap_or_default())
+        ).collect();
+        let key = (source.clone(), params.clone(), ctx.user.clone());
+        let cached = ctx.state.read().enum_cache.get(&key).and_then(|(time, output)| match arg.enum_cache_ttl {
+            Some(ttl) if time.elapsed() > Duration::from_secs(ttl) => None,
+            _ => Some(output.clone())
+        });
+
+        let output = match cached {
+            Some(output) => output,
+            None => {
+                let output = run_source(arg, source, params, ctx).await?;
+                let mut state = ctx.state.write();
+                // Choices for other values expire too, so they're dropped instead of piling up
+                if let Some(ttl) = arg.enum_cache_ttl {
+                    state.enum_cache.retain(|(cached_source, ..), (time, _)| cached_source != source || time.elapsed() <= Duration::from_secs(ttl));
+                }
+                state.enum_cache.insert(key, (Instant::now(), output.clone()));
+                output
+            }
+        };
+        parse_choices(arg, &output).map(Some)
+    }.boxed_local()
+}
+
+/// Runs an enum source with the given arguments, and returns its output.
+async fn run_source(arg: &Argument, source: &str, params: Vec<(String, Vec<String>)>, ctx: &Context<'_>) -> Result<Vec<u8>, ArgumentError> {
+    let misconfigured = |e| ArgumentError::Misconfigured(format!("Cannot load choices of argument {}: {}", arg.name, e));
+    let (task, secrets) = {
+        let state = ctx.state.read();
+        match state.config.tasks.get(source) {
+            Some(task) => (task.clone(), state.config.secrets.clone()),
+            None => return Err(misconfigured(format!("task {} doesn't exist", source)))
+        }
+    };
+
+    // The source's arguments are validated just as if someone ran it
+    let mut given: HashMap<_, _> = params.into_iter().collect();
+    let mut source_ctx = Context::new(ctx.state, source, ctx.user.clone());
+    let mut variables = HashMap::new();
+    for source_arg in &task.arguments {
+        let values = resolve(source_arg, given.remove(&source_arg.name).unwrap_or_default(), &source_ctx).await?;
+        source_ctx.values.insert(source_arg.name.clone(), values.clone());
+        variables.insert(source_arg.name.clone(), Variable { values, prefix: source_arg.prefix.clone() });
+    }
+    if !variables.contains_key("taru_user") {
+        variables.insert("taru_user".to_owned(), ctx.user.clone().unwrap_or_default().into());
+    }
+
+    let invocation = Invocation::prepare(&task, &secrets, &variables).map_err(|e| misconfigured(format!("{:#}", e)))?;
+    match capture(invocation, ENUM_SOURCE_TIMEOUT).await {
+        Ok(Some((Some(0), output))) => Ok(output),
+        Ok(Some((code, _))) => Err(ArgumentError::Invalid(format!("Loading choices of argument {} failed with exit code {}.", arg.name, code.unwrap_or(-1)))),
+        Ok(None) => Err(ArgumentError::Misconfigured(format!("Loading choices of argument {} took longer than {} seconds.", arg.name, ENUM_SOURCE_TIMEOUT.as_secs()))),
+        Err(e) => Err(misconfigured(e.to_string()))
+    }
+}
+
+fn check_range(arg: &Argument, number: f64) -> Result<(), ArgumentError> {
+    if let Some(min) = arg.min {
+        if number < min {
+            return Err(ArgumentError::Invalid(format!("Argument {} can't be less than {}.", arg.name, min)));
+        }
+    }
+    if let Some(max) = arg.max {
+        if number > max {
+            return Err(ArgumentError::Invalid(format!("Argument {} can't be more than {}.", arg.name, max)));
+        }
+    }
+    Ok(())
+}
+
+/// Removes `.` components, and refuses `..`, without touching the filesystem.
+fn normalize(path: &Path) -> Option<PathBuf> {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {},
+            Component::ParentDir => return None,
+            component => normalized.push(component),
+        }
+    }
+    Some(normalized)
+}
+
+fn validate_path(arg: &Argument, value: &str) -> Result<String, ArgumentError> {
+    if arg.base_dirs.is_empty() {
+        return Err(ArgumentError::Misconfigured(format!("Argument {} is a path without base_dirs, please fix its configuration.", arg.name)));
+    }
+    let invalid = || ArgumentError::Invalid(format!("Argument {} has to be a path inside of: {}.", arg.name, arg.base_dirs.join(", ")));
+    if value.is_empty() || value.contains('\0') {
+        return Err(invalid());
+    }
+
+    // Relative paths are relative to the first base directory
+    let path = normalize(&Path::new(&arg.base_dirs[0]).join(value)).ok_or_else(invalid)?;
+    let base = arg.base_dirs.iter().filter_map(|base| normalize(Path::new(base))).find(|base| path.starts_with(base));
+    let base = base.ok_or_else(invalid)?;
+
+    // Symlinks could still point outside of the base directory, even if the path itself doesn't exist yet.
+    // A dangling symlink is refused, since whatever it points to could be created through it.
+    let mut inside = path.ancestors().take_while(|ancestor| ancestor.starts_with(&base));
+    if let Some(existing) = inside.find(|ancestor| ancestor.symlink_metadata().is_ok()) {
+        let canonical = existing.canonicalize().map_err(|_| invalid())?;
+        let canonical_base = base.canonicalize().map_err(|_| invalid())?;
+        if !canonical.starts_with(canonical_base) {
+            return Err(invalid());
+        }
+    }
+
+    path.into_os_string().into_string().map_err(|_| invalid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path_argument(base: &Path) -> Argument {
+        serde_yaml::from_str(&format!("{{name: path, datatype: Path, base_dirs: [{}]}}", base.display())).unwrap()
+    }
+
+    #[test]
+    fn symlinks_outside_are_refused() {
+        let dir = tempfile::tempdir().unwrap();
+        let (base, outside) = (dir.path().join("base"), dir.path().join("outside"));
+        std::fs::create_dir(&base).unwrap();
+        std::fs::create_dir(&outside).unwrap();
+        std::os::unix::fs::symlink(&outside, base.join("link")).unwrap();
+        std::os::unix::fs::symlink(outside.join("missing"), base.join("dangling")).unwrap();
+        let arg = path_argument(&base);
+
+        assert!(validate_path(&arg, "new/file").is_ok());
+        assert!(validate_path(&arg, "link").is_err());
+        // Neither can be created without writing outside of the base directory
+        assert!(validate_path(&arg, "link/new/file").is_err());
+        assert!(validate_path(&arg, "dangling").is_err());
+    }
+}
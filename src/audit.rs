@@ -0,0 +1,302 @@
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::net::SocketAddr;
+use std::os::unix::fs::OpenOptionsExt;
+use std::os::unix::net::UnixDatagram;
+use std::sync::{Arc, mpsc};
+use actix_web::{HttpRequest, HttpMessage, web::Data, dev::ServiceRequest, http::{HeaderMap, StatusCode}};
+use parking_lot::{Mutex, RwLock};
+use serde::Serialize;
+use chrono::{DateTime, Utc};
+use anyhow::Context;
+
+use crate::app_state::AppState;
+use crate::auth::Identity;
+use crate::cfg::AuditConfig;
+use crate::task::ArgumentValue;
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all="lowercase")]
+pub enum Outcome {
+    Success,
+    Denied,
+    Failed,
+}
+
+impl Outcome {
+    pub fn of(status: StatusCode) -> Self {
+        match status {
+            status if status.is_success() => Outcome::Success,
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN | StatusCode::NOT_FOUND => Outcome::Denied,
+            _ => Outcome::Failed
+        }
+    }
+}
+
+/// Something that has happened, written to the audit log as a line of JSON.
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub timestamp: DateTime<Utc>,
+    pub action: &'static str,
+    pub outcome: Outcome,
+    /// The status of the response, for actions requested over HTTP.
+    pub status: Option<u16>,
+    pub user: Option<String>,
+    /// The name of the token the user has authenticated with.
+    pub token: Option<String>,
+    pub address: Option<String>,
+    /// What the client, or a proxy, claims the address to be.
+    pub forwarded_for: Option<String>,
+    pub task: Option<String>,
+    /// Sensitive values are always masked.
+    pub arguments: Option<HashMap<String, ArgumentValue>>,
+    pub detail: Option<String>,
+}
+
+impl Record {
+    pub fn new(action: &'static str, outcome: Outcome) -> Self {
+        Record {
+            timestamp: Utc::now(),
+            action,
+            outcome,
+            status: None,
+            user: None,
+            token: None,
+            address: None,
+            forwarded_for: None,
+            task: None,
+            arguments: None,
+            detail: None,
+        }
+    }
+
+    fn with_client(mut self, headers: &HeaderMap, peer: Option<SocketAddr>, identity: Option<&Identity>) -> Self {
+        self.address = peer.map(|peer| peer.ip().to_string());
+        self.forwarded_for = headers.get("x-forwarded-for").and_then(|h| h.to_str().ok()).map(String::from);
+        if let Some(identity) = identity.filter(|identity| !identity.anonymous) {
+            self.user = Some(identity.login.clone());
+            self.token = identity.token.clone();
+        }
+        self
+    }
+
+    /// Records who has made a request, and where from.
+    pub fn request(action: &'static str, outcome: Outcome, req: &HttpRequest) -> Self {
+        Record::new(action, outcome).with_client(req.headers(), req.peer_addr(), req.extensions().get::<Identity>())
+    }
+
+    pub fn service_request(action: &'static str, outcome: Outcome, req: &ServiceRequest) -> Self {
+        Record::new(action, outcome).with_client(req.headers(), req.peer_addr(), req.extensions().get::<Identity>())
+    }
+
+    /// Records the status of a response, and the outcome it means.
+    pub fn status(mut self, status: StatusCode) -> Self {
+        self.status = Some(status.as_u16());
+        self.outcome = Outcome::of(status);
+        self
+    }
+
+    pub fn task(mut self, task: impl Into<String>) -> Self {
+        self.task = Some(task.into());
+        self
+    }
+
+    pub fn arguments(mut self, arguments: HashMap<String, ArgumentValue>) -> Self {
+        self.arguments = Some(arguments);
+        self
+    }
+
+    pub fn user(mut self, user: Option<String>) -> Self {
+        self.user = user;
+        self
+    }
+
+    pub fn detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+}
+
+struct LogFile {
+    path: String,
+    file: File,
+    size: u64,
+}
+
+fn open(path: &str) -> anyhow::Result<File> {
+    OpenOptions::new().create(true).append(true).mode(0o600).open(path).with_context(|| format!("Cannot open {}", path))
+}
+
+impl LogFile {
+    /// Moves the file to `PATH.1`, the one before to `PATH.2`, and so on, dropping the oldest.
+    fn rotate(&mut self, keep: usize) -> anyhow::Result<()> {
+        if keep == 0 {
+            self.file.set_len(0).with_context(|| format!("Cannot truncate {}", self.path))?;
+        } else {
+            for i in (1..keep).rev() {
+                let older = format!("{}.{}", self.path, i);
+                if std::path::Path::new(&older).exists() {
+                    std::fs::rename(&older, format!("{}.{}", self.path, i + 1)).with_context(|| format!("Cannot rotate {}", older))?;
+                }
+            }
+            std::fs::rename(&self.path, format!("{}.1", self.path)).with_context(|| format!("Cannot rotate {}", self.path))?;
+            self.file = open(&self.path)?;
+        }
+        self.size = 0;
+        Ok(())
+    }
+}
+
+/// Sends a record to the journal over its native protocol, with a few fields to filter on.
+fn journal(action: &str, outcome: Outcome, line: &[u8]) -> std::io::Result<()> {
+    let mut message = Vec::with_capacity(line.len() + 128);
+    message.extend_from_slice(b"SYSLOG_IDENTIFIER=taru\nPRIORITY=5\n");
+    writeln!(message, "TARU_AUDIT_ACTION={}", action)?;
+    writeln!(message, "TARU_AUDIT_OUTCOME={}", serde_json::to_value(outcome).unwrap().as_str().unwrap())?;
+    // JSON never contains a newline, so it doesn't need the length-prefixed encoding
+    message.extend_from_slice(b"MESSAGE=");
+    message.extend_from_slice(line);
+    UnixDatagram::unbound()?.send_to(&message, "/run/systemd/journal/socket")?;
+    Ok(())
+}
+
+enum Message {
+    Record { action: &'static str, outcome: Outcome, line: Vec<u8> },
+    /// The file is only set if the path has changed, otherwise the one already open is kept.
+    Configure { config: Option<AuditConfig>, file: Option<Option<LogFile>> },
+}
+
+/// Writes the records on a thread of its own, so that no request waits for the disk.
+fn write_records(messages: mpsc::Receiver<Message>) {
+    let mut config = None;
+    let mut file: Option<LogFile> = None;
+    for message in messages {
+        let (action, outcome, line) = match message {
+            Message::Record { action, outcome, line } => (action, outcome, line),
+            Message::Configure { config: new_config, file: new_file } => {
+                config = new_config;
+                if let Some(new_file) = new_file {
+                    file = new_file;
+                }
+                continue
+            }
+        };
+        let config: &AuditConfig = match config {
+            Some(ref config) => config,
+            None => continue
+        };
+
+        if config.journald {
+            if let Err(e) = journal(action, outcome, &line) {
+                eprintln!("Cannot write to the journal: {}", e);
+            }
+        }
+        if let Some(ref mut file) = file {
+            if file.size > 0 && file.size + line.len() as u64 > config.max_size {
+                if let Err(e) = file.rotate(config.keep) {
+                    eprintln!("Cannot rotate the audit log: {:#}", e);
+                }
+            }
+            match file.file.write_all(&line) {
+                Ok(()) => file.size += line.len() as u64,
+                Err(e) => eprintln!("Cannot write to the audit log: {}", e)
+            }
+        }
+    }
+}
+
+/// An append-only log of privileged actions.
+pub struct AuditLog {
+    /// The path of the file being written to, which stays open across reloads that don't change it.
+    path: Mutex<Option<String>>,
+    sender: Mutex<mpsc::Sender<Message>>,
+}
+
+impl AuditLog {
+    pub fn new(config: Option<&AuditConfig>) -> anyhow::Result<Self> {
+        let (sender, receiver) = mpsc::channel();
+        std::thread::Builder::new().name("audit".to_owned()).spawn(move || write_records(receiver))
+            .context("Cannot start writing the audit log")?;
+        let audit = AuditLog { path: Mutex::new(None), sender: Mutex::new(sender) };
+        audit.configure(config)?;
+        Ok(audit)
+    }
+
+    /// Applies the configuration to the records written from now on.
+    pub fn configure(&self, config: Option<&AuditConfig>) -> anyhow::Result<()> {
+        let mut path = self.path.lock();
+        let new_path = config.and_then(|config| config.file.clone());
+        let file = match new_path {
+            _ if new_path == *path => None,
+            Some(ref new_path) => {
+                let file = open(new_path)?;
+                let size = file.metadata().with_context(|| format!("Cannot read {}", new_path))?.len();
+                Some(Some(LogFile { path: new_path.clone(), file, size }))
+            },
+            None => Some(None)
+        };
+        self.send(Message::Configure { config: config.cloned(), file });
+        *path = new_path;
+        Ok(())
+    }
+
+    pub fn write(&self, record: &Record) {
+        let mut line = serde_json::to_vec(record).unwrap();
+        line.push(b'\n');
+        self.send(Message::Record { action: record.action, outcome: record.outcome, line });
+    }
+
+    fn send(&self, message: Message) {
+        if self.sender.lock().send(message).is_err() {
+            eprintln!("Cannot write to the audit log: it has stopped");
+        }
+    }
+}
+
+/// Writes a record to the audit log of the application a request has been made to.
+pub fn log(req: &HttpRequest, record: Record) {
+    let data: Option<&Data<Arc<RwLock<AppState>>>> = req.app_data();
+    if let Some(data) = data {
+        log_to(data, record);
+    }
+}
+
+pub fn log_to(data: &Arc<RwLock<AppState>>, record: Record) {
+    // The log is written to without holding the lock on the state
+    let audit = data.read().audit.clone();
+    audit.write(&record);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wait_for_lines(path: &std::path::Path, count: usize) -> String {
+        for _ in 0..100 {
+            let content = std::fs::read_to_string(path).unwrap_or_default();
+            if content.lines().count() >= count {
+                return content
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        panic!("{} hasn't got {} lines", path.display(), count)
+    }
+
+    #[test]
+    fn file_is_kept_open_when_reconfigured() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.log");
+        let mut config = AuditConfig { file: Some(path.to_str().unwrap().to_owned()), max_size: 1 << 20, keep: 1, journald: false };
+        let audit = AuditLog::new(Some(&config)).unwrap();
+        audit.write(&Record::new("first", Outcome::Success));
+        let line = wait_for_lines(&path, 1);
+
+        // The size of what has been written so far counts towards rotating
+        config.max_size = line.len() as u64 + 1;
+        audit.configure(Some(&config)).unwrap();
+        audit.write(&Record::new("second", Outcome::Success));
+        assert_eq!(wait_for_lines(&dir.path().join("audit.log.1"), 1), line);
+        assert!(wait_for_lines(&path, 1).contains("\"second\""));
+    }
+}
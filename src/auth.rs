@@ -0,0 +1,500 @@
+use actix_web::{web, get, post, HttpRequest, HttpResponse, HttpMessage, FromRequest, dev::ServiceRequest, http::StatusCode};
+use actix_web::client::Client;
+use actix_session::{Session, UserSession};
+use parking_lot::RwLock;
+use serde::Deserialize;
+use serde_json::Value;
+use chrono::Utc;
+use rand::{Rng, distributions::Alphanumeric};
+use std::sync::Arc;
+use anyhow::{Context, anyhow, bail};
+use ring::constant_time::verify_slices_are_equal;
+
+use crate::app_state::AppState;
+use crate::cfg::{Config, AuthConfig, AuthMode, OidcConfig, ProxyConfig, TaskEntry};
+use crate::tokens;
+use crate::passwords;
+use crate::tls::ClientCertificate;
+use crate::audit::{self, Record, Outcome};
+
+/// Who is making a request, as established by the authentication middleware.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Identity {
+    pub login: String,
+    /// Groups the identity provider says the user is in, on top of those in the configuration.
+    pub groups: Vec<String>,
+    /// The tasks a token is limited to, if it's narrower than its user.
+    pub scope: Option<Vec<TaskEntry>>,
+    /// The name of the token the user has authenticated with, if any.
+    pub token: Option<String>,
+    /// Whether nobody has authenticated, and the `anonymous` permissions apply.
+    pub anonymous: bool,
+}
+
+impl Identity {
+    pub fn new(login: impl Into<String>, groups: Vec<String>) -> Self {
+        Identity { login: login.into(), groups, scope: None, token: None, anonymous: false }
+    }
+
+    pub fn anonymous() -> Self {
+        Identity { anonymous: true, ..Identity::new("", vec![]) }
+    }
+}
+
+/// Why a request can't go through.
+pub enum Denied {
+    /// Nobody has logged in.
+    Unauthenticated,
+    /// Someone has, but isn't allowed to use Taru.
+    Forbidden,
+    /// The request has a bearer token, but it's unknown or expired.
+    InvalidToken,
+    /// The request has a user header, but doesn't come from a trusted proxy.
+    UntrustedProxy,
+    /// The request relies on a session cookie, but lacks the session's CSRF token.
+    Csrf,
+}
+
+/// Returns the identity of the user making a request, if authentication is enabled.
+pub fn identity(req: &HttpRequest) -> Option<Identity> {
+    req.extensions().get::<Identity>().cloned()
+}
+
+/// Figures out who is making a request.
+///
+/// Returns `None` if authentication is disabled altogether.
+pub fn authenticate(req: &ServiceRequest, state: &AppState) -> Result<Option<Identity>, Denied> {
+    match identify(req, state) {
+        Err(Denied::Unauthenticated) if state.config.auth_mode() == AuthMode::Optional => Ok(Some(Identity::anonymous())),
+        result => result
+    }
+}
+
+fn identify(req: &ServiceRequest, state: &AppState) -> Result<Option<Identity>, Denied> {
+    let config = &state.config;
+    if config.auth_mode() == AuthMode::Disabled {
+        return Ok(None)
+    }
+
+    // Other kinds of authorization are left alone, a proxy could have used them
+    let bearer = req.headers().get("authorization").and_then(|h| h.to_str().ok()).and_then(|h| h.strip_prefix("Bearer "));
+    if let Some(token) = bearer {
+        return tokens::identity(state, token.trim()).map(Some).ok_or(Denied::InvalidToken)
+    }
+
+    // A client certificate is only there if it has been verified, it just might not belong to a user
+    let rules = config.tls.as_ref().map(|tls| &tls.client_users[..]).unwrap_or_default();
+    let certificate = req.extensions().get::<ClientCertificate>().and_then(|certificate| certificate.login(rules));
+    if let Some(login) = certificate {
+        return match config.users.get(&login) {
+            Some(user) if !user.service_account => Ok(Some(Identity::new(login, vec![]))),
+            _ => Err(Denied::Forbidden)
+        }
+    }
+
+    let has_login = config.auth.as_ref().and_then(AuthConfig::provider).is_some() || config.has_passwords();
+    let session = if has_login { session_identity(&req.get_session()) } else { None };
+    let identity = match (session, &config.proxy) {
+        (Some((identity, csrf_token)), _) => {
+            // Browsers send the cookie along with requests other sites make them send, but not this header
+            let safe = matches!(req.method().as_str(), "GET" | "HEAD" | "OPTIONS");
+            let given = req.headers().get("x-csrf-token").map(|h| h.as_bytes()).unwrap_or_default();
+            if !safe && verify_slices_are_equal(given, csrf_token.as_bytes()).is_err() {
+                return Err(Denied::Csrf)
+            }
+            Some(identity)
+        },
+        (None, Some(proxy)) => proxy_identity(req, proxy)?,
+        // Without a login provider, a reverse proxy is assumed to authenticate users
+        (None, None) if !has_login => proxy_identity(req, &ProxyConfig::default())?,
+        (None, None) => None,
+    };
+
+    match identity {
+        // Service accounts can only use tokens
+        Some(identity) if config.users.get(&identity.login).map_or(false, |user| user.service_account) => Err(Denied::Forbidden),
+        Some(identity) if config.knows(&identity) => Ok(Some(identity)),
+        Some(_) => Err(Denied::Forbidden),
+        None => Err(Denied::Unauthenticated)
+    }
+}
+
+/// Returns the identity a reverse proxy has put in the request's headers.
+///
+/// Headers from anywhere else than the proxy are refused, so that nobody can pretend to be someone else.
+fn proxy_identity(req: &ServiceRequest, proxy: &ProxyConfig) -> Result<Option<Identity>, Denied> {
+    let login = match req.headers().get(&proxy.header[..]).and_then(|h| h.to_str().ok()) {
+        Some(login) => login,
+        None => return Ok(None)
+    };
+    if !proxy.trusted.is_empty() {
+        let peer = req.peer_addr().map(|addr| addr.ip());
+        if !peer.map_or(false, |peer| proxy.trusted.iter().any(|network| network.contains(peer))) {
+            return Err(Denied::UntrustedProxy)
+        }
+    }
+    if let (Some(header), Some(secret)) = (&proxy.secret_header, &proxy.secret) {
+        let given = req.headers().get(&header[..]).map(|h| h.as_bytes()).unwrap_or_default();
+        if verify_slices_are_equal(given, secret.as_bytes()).is_err() {
+            return Err(Denied::UntrustedProxy)
+        }
+    }
+    let groups = match proxy.groups_header {
+        Some(ref header) => req.headers().get_all(&header[..])
+            .filter_map(|h| h.to_str().ok())
+            .flat_map(|groups| groups.split(','))
+            .map(str::trim)
+            .filter(|group| !group.is_empty())
+            .map(String::from)
+            .collect(),
+        None => vec![]
+    };
+    Ok(Some(Identity::new(login, groups)))
+}
+
+/// Returns the identity of a user who has logged in, and the session's CSRF token, unless it has expired.
+fn session_identity(session: &Session) -> Option<(Identity, String)> {
+    let expires = session.get::<i64>("expires").ok()??;
+    if expires <= Utc::now().timestamp() {
+        return None
+    }
+    let identity = Identity::new(
+        session.get::<String>("login").ok()??,
+        session.get("groups").ok()?.unwrap_or_default(),
+    );
+    Some((identity, session.get("csrf_token").ok()??))
+}
+
+/// Remembers a user who has just logged in. Returns the CSRF token the session's requests have to carry.
+fn start_session(session: &Session, config: &Config, login: String, groups: Vec<String>) -> Result<String, HttpResponse> {
+    let csrf_token: String = rand::thread_rng().sample_iter(&Alphanumeric).take(32).collect();
+    let expires = Utc::now().timestamp() + config.session_lifetime() as i64;
+    session.clear();
+    let stored = session.set("login", login).is_ok()
+        && session.set("groups", groups).is_ok()
+        && session.set("expires", expires).is_ok()
+        && session.set("csrf_token", &csrf_token).is_ok();
+    if stored {
+        Ok(csrf_token)
+    } else {
+        Err(HttpResponse::InternalServerError().body("Cannot store the session."))
+    }
+}
+
+/// Returns a key for the session cookies, as configured, or a random one.
+pub fn session_key(config: &Config) -> Vec<u8> {
+    match config.auth.as_ref().and_then(|auth| auth.session_key.as_ref()) {
+        Some(key) => key.as_bytes().to_owned(),
+        None => rand::thread_rng().sample_iter(&Alphanumeric).take(64).collect::<String>().into_bytes()
+    }
+}
+
+/// Endpoints of an OpenID Connect provider.
+#[derive(Deserialize)]
+struct Discovery {
+    authorization_endpoint: String,
+    token_endpoint: String,
+    userinfo_endpoint: String,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+}
+
+async fn discover(provider: &OidcConfig) -> anyhow::Result<Discovery> {
+    let url = format!("{}/.well-known/openid-configuration", provider.issuer.trim_end_matches('/'));
+    let mut response = Client::default().get(&url).send().await.map_err(|e| anyhow!("{}", e)).with_context(|| format!("Cannot reach {}", url))?;
+    if !response.status().is_success() {
+        bail!("{} returned {}", url, response.status());
+    }
+    response.json().await.map_err(|e| anyhow!("{}", e)).with_context(|| format!("Invalid response from {}", url))
+}
+
+/// Exchanges an authorization code for the user's claims.
+async fn claims(provider: &OidcConfig, code: &str, redirect_uri: &str) -> anyhow::Result<Value> {
+    let discovery = discover(provider).await?;
+    let client = Client::default();
+
+    let mut response = client.post(&discovery.token_endpoint).send_form(&[
+        ("grant_type", "authorization_code"),
+        ("code", code),
+        ("redirect_uri", redirect_uri),
+        ("client_id", &provider.client_id),
+        ("client_secret", &provider.client_secret),
+    ]).await.map_err(|e| anyhow!("{}", e)).context("Cannot reach the token endpoint")?;
+    if !response.status().is_success() {
+        bail!("The token endpoint returned {}", response.status());
+    }
+    let token: TokenResponse = response.json().await.map_err(|e| anyhow!("{}", e)).context("Invalid response from the token endpoint")?;
+
+    // The user info comes straight from the provider, so unlike an ID token, it doesn't need its signature checked
+    let mut response = client.get(&discovery.userinfo_endpoint)
+        .bearer_auth(&token.access_token)
+        .send().await.map_err(|e| anyhow!("{}", e)).context("Cannot reach the userinfo endpoint")?;
+    if !response.status().is_success() {
+        bail!("The userinfo endpoint returned {}", response.status());
+    }
+    response.json().await.map_err(|e| anyhow!("{}", e)).context("Invalid response from the userinfo endpoint")
+}
+
+fn base_url(req: &HttpRequest, auth: &AuthConfig) -> String {
+    match auth.url {
+        Some(ref url) => url.trim_end_matches('/').to_owned(),
+        None => {
+            let info = req.connection_info();
+            format!("{}://{}", info.scheme(), info.host())
+        }
+    }
+}
+
+fn login_provider(data: &Arc<RwLock<AppState>>) -> Option<(AuthConfig, OidcConfig)> {
+    let data = data.read();
+    let auth = data.config.auth.clone()?;
+    let provider = auth.provider()?;
+    Some((auth, provider))
+}
+
+fn redirect(location: &str) -> HttpResponse {
+    HttpResponse::Found().header("location", location).finish()
+}
+
+#[derive(Deserialize)]
+pub struct LoginQuery {
+    #[serde(rename="return")]
+    return_to: Option<String>,
+}
+
+/// Only local paths, so that logging in can't be used to redirect anywhere else.
+fn local_path(path: Option<String>) -> Option<String> {
+    let path = path.filter(|path| path.starts_with('/') && !path.starts_with("//"))?;
+    // Browsers read `\` as `/`, so `/\example.com` would be another host
+    if path.contains(|c: char| c == '\\' || c.is_control()) {
+        return None
+    }
+    let base = url::Url::parse("http://taru.invalid/").unwrap();
+    let url = base.join(&path).ok().filter(|url| url.origin() == base.origin())?;
+    Some(match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_owned()
+    })
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+fn login_form(return_to: Option<&str>, error: Option<&str>) -> String {
+    let error = error.map(|error| format!("<p class=\"error\">{}</p>", escape_html(error))).unwrap_or_default();
+    format!(r#"<!DOCTYPE html>
+<html>
+<head><meta charset="utf-8"><title>Log in to Taru</title></head>
+<body>
+<form method="post" action="/auth/login">
+{}<input type="hidden" name="return" value="{}">
+<p><label>Login <input name="login" autofocus required></label></p>
+<p><label>Password <input name="password" type="password" required></label></p>
+<p><button type="submit">Log in</button></p>
+</form>
+</body>
+</html>
+"#, error, escape_html(return_to.unwrap_or("/")))
+}
+
+#[get("/login")]
+async fn auth_login(req: HttpRequest, session: Session, data: web::Data<Arc<RwLock<AppState>>>, query: web::Query<LoginQuery>) -> HttpResponse {
+    let (auth, provider) = match login_provider(&data) {
+        Some(provider) => provider,
+        None if data.read().config.has_passwords() => {
+            let form = login_form(local_path(query.return_to.clone()).as_deref(), None);
+            return HttpResponse::Ok().content_type("text/html; charset=utf-8").body(form)
+        },
+        None => return HttpResponse::NotFound().finish()
+    };
+    let discovery = match discover(&provider).await {
+        Ok(discovery) => discovery,
+        Err(e) => return HttpResponse::BadGateway().body(format!("Login failed: {:#}", e))
+    };
+
+    let state: String = rand::thread_rng().sample_iter(&Alphanumeric).take(32).collect();
+    let return_to = local_path(query.return_to.clone());
+    if session.set("oauth_state", &state).is_err() || session.set("return_to", return_to).is_err() {
+        return HttpResponse::InternalServerError().body("Cannot store the session.")
+    }
+
+    let redirect_uri = format!("{}/auth/callback", base_url(&req, &auth));
+    let scopes = provider.scopes.join(" ");
+    let url = url::Url::parse_with_params(&discovery.authorization_endpoint, &[
+        ("response_type", "code"),
+        ("client_id", &provider.client_id),
+        ("redirect_uri", &redirect_uri),
+        ("scope", &scopes),
+        ("state", &state),
+    ]);
+    match url {
+        Ok(url) => redirect(url.as_str()),
+        Err(e) => HttpResponse::BadGateway().body(format!("Login failed: invalid authorization endpoint: {}", e))
+    }
+}
+
+#[derive(Deserialize)]
+pub struct CallbackQuery {
+    code: Option<String>,
+    state: Option<String>,
+    error: Option<String>,
+}
+
+#[get("/callback")]
+async fn auth_callback(req: HttpRequest, session: Session, data: web::Data<Arc<RwLock<AppState>>>, query: web::Query<CallbackQuery>) -> HttpResponse {
+    let (auth, provider) = match login_provider(&data) {
+        Some(provider) => provider,
+        None => return HttpResponse::NotFound().finish()
+    };
+    if let Some(ref error) = query.error {
+        audit::log(&req, Record::request("login", Outcome::Denied, &req).status(StatusCode::UNAUTHORIZED).detail(format!("oidc: {}", error)));
+        return HttpResponse::Unauthorized().body(format!("Login failed: {}", error))
+    }
+    let expected: Option<String> = session.get("oauth_state").unwrap_or(None);
+    session.remove("oauth_state");
+    let code = match (&query.code, &query.state, expected) {
+        (Some(code), Some(state), Some(expected)) if *state == expected => code,
+        _ => return HttpResponse::BadRequest().body("Login failed: invalid state, please try again.")
+    };
+
+    let redirect_uri = format!("{}/auth/callback", base_url(&req, &auth));
+    let claims = match claims(&provider, code, &redirect_uri).await {
+        Ok(claims) => claims,
+        Err(e) => return HttpResponse::BadGateway().body(format!("Login failed: {:#}", e))
+    };
+    let login = match claims.get(&provider.login_claim).and_then(Value::as_str) {
+        Some(login) => login.to_owned(),
+        None => return HttpResponse::BadGateway().body(format!("Login failed: the provider didn't return a {} claim.", provider.login_claim))
+    };
+    let groups: Vec<String> = match claims.get(&provider.groups_claim) {
+        Some(Value::Array(groups)) => groups.iter().filter_map(Value::as_str).map(String::from).collect(),
+        _ => vec![]
+    };
+
+    let return_to: Option<String> = session.get("return_to").unwrap_or(None);
+    let record = Record::request("login", Outcome::Success, &req).user(Some(login.clone())).detail("oidc");
+    if let Err(response) = start_session(&session, &data.read().config, login, groups) {
+        audit::log(&req, record.status(response.status()));
+        return response
+    }
+    audit::log(&req, record.status(StatusCode::OK));
+    redirect(return_to.as_deref().unwrap_or("/"))
+}
+
+#[derive(Deserialize)]
+pub struct PasswordLogin {
+    login: String,
+    password: String,
+    #[serde(rename="return")]
+    return_to: Option<String>,
+}
+
+/// Logs a user in with a password, sent either from the login form, or as JSON.
+#[post("/login")]
+async fn auth_password_login(req: HttpRequest, session: Session, data: web::Data<Arc<RwLock<AppState>>>, body: web::Payload) -> HttpResponse {
+    let config = data.read().config.clone();
+    if !config.has_passwords() {
+        return HttpResponse::NotFound().finish()
+    }
+    let is_json = req.headers().get("content-type").and_then(|h| h.to_str().ok()).unwrap_or_default().starts_with("application/json");
+    let credentials = if is_json {
+        web::Json::<PasswordLogin>::from_request(&req, &mut body.into_inner()).await.map(web::Json::into_inner)
+    } else {
+        web::Form::<PasswordLogin>::from_request(&req, &mut body.into_inner()).await.map(web::Form::into_inner)
+    };
+    let credentials = match credentials {
+        Ok(credentials) => credentials,
+        Err(e) => return HttpResponse::BadRequest().body(format!("Invalid login request: {}", e))
+    };
+    let return_to = local_path(credentials.return_to);
+
+    let login = credentials.login.clone();
+    let password = credentials.password;
+    let record = Record::request("login", Outcome::Success, &req).user(Some(login.clone())).detail("password");
+    let checked = {
+        let config = config.clone();
+        let login = login.clone();
+        web::block(move || passwords::check(&config, &login, &password)).await
+    };
+    match checked {
+        Ok(true) => {},
+        Ok(false) => {
+            audit::log(&req, record.status(StatusCode::UNAUTHORIZED));
+            let error = "Invalid login or password.";
+            return if is_json {
+                HttpResponse::Unauthorized().body(error)
+            } else {
+                HttpResponse::Unauthorized().content_type("text/html; charset=utf-8").body(login_form(return_to.as_deref(), Some(error)))
+            }
+        },
+        Err(e) => {
+            audit::log(&req, record.status(StatusCode::INTERNAL_SERVER_ERROR));
+            return HttpResponse::InternalServerError().body(format!("Cannot check the password: {}", e))
+        }
+    }
+
+    let csrf_token = match start_session(&session, &config, login.clone(), vec![]) {
+        Ok(csrf_token) => csrf_token,
+        Err(response) => {
+            audit::log(&req, record.status(response.status()));
+            return response
+        }
+    };
+    audit::log(&req, record.status(StatusCode::OK));
+    if is_json {
+        HttpResponse::Ok().json(serde_json::json!({ "login": login, "csrf_token": csrf_token }))
+    } else {
+        redirect(return_to.as_deref().unwrap_or("/"))
+    }
+}
+
+/// Tells the web interface who has logged in, and the CSRF token it has to send.
+#[get("/session")]
+async fn auth_session(session: Session) -> HttpResponse {
+    match session_identity(&session) {
+        Some((identity, csrf_token)) => HttpResponse::Ok().json(serde_json::json!({
+            "login": identity.login,
+            "groups": identity.groups,
+            "expires": session.get::<i64>("expires").unwrap_or(None),
+            "csrf_token": csrf_token,
+        })),
+        None => HttpResponse::Unauthorized().body("Please log in.")
+    }
+}
+
+#[post("/logout")]
+async fn auth_logout(req: HttpRequest, session: Session) -> HttpResponse {
+    if let Some((identity, _)) = session_identity(&session) {
+        audit::log(&req, Record::request("logout", Outcome::Success, &req).user(Some(identity.login)));
+    }
+    // Purging would remove the cookie without its path, which browsers ignore, so empty it instead
+    session.clear();
+    redirect("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::local_path;
+
+    fn check(path: &str) -> Option<String> {
+        local_path(Some(path.to_owned()))
+    }
+
+    #[test]
+    fn local_paths_are_kept() {
+        assert_eq!(check("/").as_deref(), Some("/"));
+        assert_eq!(check("/task/deploy?env=prod").as_deref(), Some("/task/deploy?env=prod"));
+        assert_eq!(check("/a/../b").as_deref(), Some("/b"));
+    }
+
+    #[test]
+    fn other_hosts_are_refused() {
+        for path in ["//evil.example", "/\\evil.example", "/\\/evil.example", "https://evil.example", "evil.example", "/\t/evil.example", "/%0a/x\n"] {
+            assert_eq!(check(path), None, "{}", path);
+        }
+    }
+}
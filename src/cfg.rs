@@ -1,9 +1,249 @@
-use std::collections::HashMap;
-use serde::{Serialize, Deserialize};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use regex::Regex;
+use chrono::{DateTime, Utc};
+use anyhow::{Context, bail};
+
+use crate::cmdline;
+use crate::auth::Identity;
+use crate::passwords;
 use std::fs::File;
 use serde_yaml;
 use serde_json;
 
+/// A regular expression, checked when the configuration is loaded.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    regex: Regex,
+    anchored: Regex,
+    /// For matching output, which doesn't have to be UTF-8.
+    bytes: regex::bytes::Regex,
+}
+
+impl Pattern {
+    pub fn as_str(&self) -> &str {
+        self.regex.as_str()
+    }
+
+    pub fn bytes(&self) -> &regex::bytes::Regex {
+        &self.bytes
+    }
+
+    /// Whether the pattern matches the whole value, not just a part of it.
+    pub fn matches_whole(&self, value: &str) -> bool {
+        self.anchored.is_match(value)
+    }
+
+    /// The capture groups of a match of the whole value.
+    pub fn captures_whole<'t>(&self, value: &'t str) -> Option<regex::Captures<'t>> {
+        self.anchored.captures(value)
+    }
+}
+
+impl PartialEq for Pattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Pattern {}
+
+impl Serialize for Pattern {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Pattern {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pattern = String::deserialize(deserializer)?;
+        Ok(Pattern {
+            regex: Regex::new(&pattern).map_err(serde::de::Error::custom)?,
+            anchored: Regex::new(&format!("^(?:{})$", pattern)).map_err(serde::de::Error::custom)?,
+            bytes: regex::bytes::Regex::new(&pattern).map_err(serde::de::Error::custom)?,
+        })
+    }
+}
+
+/// An IP network, e.g. `10.0.0.0/8`, or a single address.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cidr {
+    address: IpAddr,
+    prefix: u32,
+}
+
+impl Cidr {
+    pub fn contains(&self, address: IpAddr) -> bool {
+        // IPv4 clients of an IPv6 socket show up as mapped addresses
+        let address = match address {
+            IpAddr::V6(v6) => v6.to_ipv4_mapped().map(IpAddr::V4).unwrap_or(address),
+            address => address
+        };
+        match (self.address, address) {
+            (IpAddr::V4(network), IpAddr::V4(address)) => {
+                let mask = u32::MAX.checked_shl(32 - self.prefix).unwrap_or(0);
+                u32::from(network) & mask == u32::from(address) & mask
+            },
+            (IpAddr::V6(network), IpAddr::V6(address)) => {
+                let mask = u128::MAX.checked_shl(128 - self.prefix).unwrap_or(0);
+                u128::from(network) & mask == u128::from(address) & mask
+            },
+            _ => false
+        }
+    }
+}
+
+impl Serialize for Cidr {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("{}/{}", self.address, self.prefix))
+    }
+}
+
+impl<'de> Deserialize<'de> for Cidr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let cidr = String::deserialize(deserializer)?;
+        let invalid = || serde::de::Error::custom(format!("`{}` is not a valid address or network", cidr));
+        let (address, prefix) = match cidr.split_once('/') {
+            Some((address, prefix)) => (address, Some(prefix)),
+            None => (&cidr[..], None)
+        };
+        let address: IpAddr = address.parse().map_err(|_| invalid())?;
+        let max = if address.is_ipv4() { 32 } else { 128 };
+        let prefix = match prefix {
+            Some(prefix) => prefix.parse().ok().filter(|prefix| *prefix <= max).ok_or_else(invalid)?,
+            None => max
+        };
+        Ok(Cidr { address, prefix })
+    }
+}
+
+fn default_user_header() -> String {
+    "X-User".to_owned()
+}
+
+/// Whether users have to authenticate.
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone, Copy)]
+pub enum AuthMode {
+    /// Everyone can do everything, and nobody is asked who they are.
+    Disabled,
+    /// Users can authenticate, but those who don't get the `anonymous` permissions.
+    Optional,
+    /// Only users who have authenticated are let in.
+    Required,
+}
+
+/// A part of a client certificate that can identify a user.
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone, Copy)]
+pub enum CertificateField {
+    CommonName,
+    DnsName,
+    Email,
+    Uri,
+}
+
+/// Maps a name from a client certificate to a user.
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+pub struct CertificateRule {
+    pub field: CertificateField,
+    /// A regular expression the whole name has to match. Any name matches if it's not set.
+    pub pattern: Option<Pattern>,
+    /// The user's login, which can refer to the pattern's capture groups as `$1`, `$name`, etc.
+    /// The name itself is used if it's not set.
+    pub user: Option<String>,
+}
+
+impl CertificateRule {
+    /// Returns the login a name maps to, if the rule applies to it.
+    pub fn login(&self, field: CertificateField, name: &str) -> Option<String> {
+        if field != self.field {
+            return None
+        }
+        let pattern = match self.pattern {
+            Some(ref pattern) => pattern,
+            None => return Some(self.user.clone().unwrap_or_else(|| name.to_owned()))
+        };
+        let captures = pattern.captures_whole(name)?;
+        Some(match self.user {
+            Some(ref user) => {
+                let mut login = String::new();
+                captures.expand(user, &mut login);
+                login
+            },
+            None => name.to_owned()
+        })
+    }
+}
+
+fn default_certificate_rules() -> Vec<CertificateRule> {
+    vec![CertificateRule { field: CertificateField::CommonName, pattern: None, user: None }]
+}
+
+/// Serving Taru over HTTPS, and authenticating clients with certificates.
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+pub struct TlsConfig {
+    /// A PEM file with the server's certificate chain.
+    pub certificate: String,
+    /// A PEM file with the server's private key.
+    pub key: String,
+    /// A PEM file with the authorities client certificates have to be issued by.
+    /// Clients aren't asked for certificates if it's not set.
+    pub client_ca: Option<String>,
+    /// Whether connections without a client certificate are refused.
+    #[serde(default)]
+    pub require_client_certificate: bool,
+    /// How certificates are mapped to users. The first rule that applies wins.
+    #[serde(default="default_certificate_rules")]
+    pub client_users: Vec<CertificateRule>,
+}
+
+/// Where privileged actions are recorded, as lines of JSON.
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+pub struct AuditConfig {
+    /// A file the records are appended to.
+    pub file: Option<String>,
+    /// The size in bytes after which the file is rotated.
+    #[serde(default="default_audit_max_size")]
+    pub max_size: u64,
+    /// How many rotated files are kept, as `FILE.1`, `FILE.2`, and so on.
+    #[serde(default="default_audit_keep")]
+    pub keep: usize,
+    /// Whether the records are also sent to the systemd journal.
+    #[serde(default)]
+    pub journald: bool,
+}
+
+fn default_audit_max_size() -> u64 {
+    100 * 1024 * 1024
+}
+
+fn default_audit_keep() -> usize {
+    5
+}
+
+/// How requests coming from a reverse proxy that authenticates users are recognized.
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+pub struct ProxyConfig {
+    /// The header the proxy puts the user's login in.
+    #[serde(default="default_user_header")]
+    pub header: String,
+    /// Addresses the proxy connects from. Any if empty.
+    #[serde(default)]
+    pub trusted: Vec<Cidr>,
+    /// A header with a comma-separated list of groups the user is in.
+    pub groups_header: Option<String>,
+    /// A header the proxy sets to `secret` in every request.
+    pub secret_header: Option<String>,
+    pub secret: Option<String>,
+}
+
+impl Default for ProxyConfig {
+    fn default() -> Self {
+        ProxyConfig { header: default_user_header(), trusted: vec![], groups_header: None, secret_header: None, secret: None }
+    }
+}
+
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
 pub struct OAuthConfig {
     pub app_id: String,
@@ -11,15 +251,86 @@ pub struct OAuthConfig {
     pub base_url: String
 }
 
+fn default_session_lifetime() -> u64 {
+    12 * 60 * 60
+}
+
+fn default_scopes() -> Vec<String> {
+    vec!["openid".to_owned(), "profile".to_owned()]
+}
+
+fn default_login_claim() -> String {
+    "preferred_username".to_owned()
+}
+
+fn default_groups_claim() -> String {
+    "groups".to_owned()
+}
+
+/// An OpenID Connect provider users log in with.
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+pub struct OidcConfig {
+    /// The provider's address, where `/.well-known/openid-configuration` can be found.
+    pub issuer: String,
+    pub client_id: String,
+    pub client_secret: String,
+    #[serde(default="default_scopes")]
+    pub scopes: Vec<String>,
+    /// The claim holding the user's login.
+    #[serde(default="default_login_claim")]
+    pub login_claim: String,
+    /// The claim holding a list of groups, matched against the names of groups in the configuration.
+    #[serde(default="default_groups_claim")]
+    pub groups_claim: String,
+}
+
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
 pub struct AuthConfig {
-    pub gitlab: Option<OAuthConfig>
+    pub gitlab: Option<OAuthConfig>,
+    pub oidc: Option<OidcConfig>,
+    /// The address Taru is reachable at, used to build redirects. Taken from requests by default.
+    pub url: Option<String>,
+    /// The key session cookies are encrypted with, at least 32 bytes long.
+    /// A random one is used if it's not set, so sessions don't survive restarts.
+    pub session_key: Option<String>,
+    /// How long a login lasts, in seconds.
+    #[serde(default="default_session_lifetime")]
+    pub session_lifetime: u64,
+    /// A file of `login:hash` lines, like an htpasswd file, checked on top of users' `password`.
+    pub password_file: Option<String>,
+}
+
+impl AuthConfig {
+    /// Returns the provider users log in with, if any.
+    ///
+    /// GitLab is an OpenID Connect provider too, it just calls the login `nickname`.
+    pub fn provider(&self) -> Option<OidcConfig> {
+        if let Some(ref gitlab) = self.gitlab {
+            return Some(OidcConfig {
+                issuer: gitlab.base_url.clone(),
+                client_id: gitlab.app_id.clone(),
+                client_secret: gitlab.secret.clone(),
+                scopes: default_scopes(),
+                login_claim: "nickname".to_owned(),
+                groups_claim: default_groups_claim(),
+            })
+        }
+        self.oidc.clone()
+    }
 }
 
 pub fn default_buffered() -> bool {
     true
 }
 
+pub fn default_replacement() -> String {
+    "[REDACTED]".to_owned()
+}
+
+pub fn default_approval_timeout() -> u64 {
+    60 * 60
+}
+
 pub fn default_meta() -> serde_json::Value {
     serde_json::Value::Null
 }
@@ -28,23 +339,174 @@ pub fn default_meta() -> serde_json::Value {
 pub enum ArgumentType {
     Enum,
     Int,
-    String
+    Float,
+    Bool,
+    String,
+    Path,
+    Date,
+    DateTime,
+    File,
 }
 
 impl Default for ArgumentType {
     fn default() -> Self { ArgumentType::Enum }
 }
 
+/// One of the values an `Enum` accepts, with a human-readable label.
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+#[serde(from = "ChoiceDef")]
+pub struct Choice {
+    pub value: String,
+    pub label: String,
+}
+
+/// Choices can be given as plain values, or as `{value, label}` objects.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ChoiceDef {
+    Value(String),
+    Labelled {
+        value: String,
+        label: Option<String>,
+    },
+}
+
+impl From<ChoiceDef> for Choice {
+    fn from(choice: ChoiceDef) -> Self {
+        match choice {
+            ChoiceDef::Value(value) => Choice { label: value.clone(), value },
+            ChoiceDef::Labelled { value, label } => Choice { label: label.unwrap_or_else(|| value.clone()), value },
+        }
+    }
+}
+
+/// How to read choices from the output of an `enum_source`, or an `enum_file`.
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone, Default)]
+pub enum EnumFormat {
+    /// One value per line.
+    #[default]
+    Lines,
+    /// A JSON list of values, or of `{"value": ..., "label": ...}` objects.
+    Json,
+}
+
+/// Accepts any scalar, so that e.g. `default: 5` doesn't have to be quoted.
+fn deserialize_scalar<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    match Option::<serde_yaml::Value>::deserialize(deserializer)? {
+        None | Some(serde_yaml::Value::Null) => Ok(None),
+        Some(serde_yaml::Value::String(value)) => Ok(Some(value)),
+        Some(serde_yaml::Value::Number(value)) => Ok(Some(value.to_string())),
+        Some(serde_yaml::Value::Bool(value)) => Ok(Some(value.to_string())),
+        Some(_) => Err(serde::de::Error::custom("expected a single value")),
+    }
+}
+
+pub fn default_required() -> bool {
+    true
+}
+
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
 pub struct Argument {
     pub name: String,
     pub datatype: ArgumentType,
+    /// A human-readable name to show instead of `name`.
+    pub label: Option<String>,
+    pub description: Option<String>,
+    /// Used when the argument isn't given, or is empty.
+    #[serde(default, deserialize_with="deserialize_scalar")]
+    pub default: Option<String>,
+    /// Command segments referring to an optional argument without a value are dropped.
+    #[serde(default="default_required")]
+    pub required: bool,
+    /// Whether the argument accepts a list of values, each becoming a separate segment of the command.
+    #[serde(default)]
+    pub multiple: bool,
+    /// A separate segment to put before every segment referring to the argument, e.g. `--service`.
+    pub prefix: Option<String>,
+    /// Whether the argument's values are hidden from users who can't view a task's arguments.
+    #[serde(default)]
+    pub sensitive: bool,
     pub enum_source: Option<String>,
+    /// A file to read the choices of an `Enum` from, instead of a task's output.
+    pub enum_file: Option<String>,
+    #[serde(default)]
+    pub enum_format: EnumFormat,
+    /// Earlier arguments whose values are passed to the `enum_source` task as its own arguments.
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+    /// How long, in seconds, to keep the choices of an `enum_source` with `depends_on`.
+    /// They're kept until the configuration is reloaded by default.
+    pub enum_cache_ttl: Option<u64>,
+    /// Another task's data the argument defaults to, or is restricted to.
+    pub data_source: Option<DataSource>,
+    /// A command checking each value after it passes the other validations.
+    pub validator: Option<Validator>,
+    /// Static choices of an `Enum`, used instead of `enum_source`.
+    #[serde(default)]
+    pub values: Vec<Choice>,
+    /// A regular expression a `String` has to match in whole.
+    pub pattern: Option<Pattern>,
+    pub max_length: Option<usize>,
+    /// Bounds of an `Int` or a `Float`, inclusive.
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    /// Directories a `Path` has to be inside of.
+    #[serde(default)]
+    pub base_dirs: Vec<String>,
+    /// The maximum size of a `File`, in bytes.
+    pub max_size: Option<u64>,
+    /// MIME types a `File` can have, any if empty.
+    #[serde(default)]
+    pub content_types: Vec<String>,
+}
+
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum SecretSource {
+    /// A path to a file containing the secret.
+    File(String),
+    /// The name of a systemd credential, read from `$CREDENTIALS_DIRECTORY`.
+    Credential(String),
+}
+
+/// A value of another task's data, set with `POST /task/{task}/data/{name}`.
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+pub struct DataSource {
+    pub task: String,
+    pub name: String,
+    /// Whether the argument can't have any other value, instead of just defaulting to this one.
+    #[serde(default)]
+    pub restrict: bool,
+}
+
+fn default_validator_timeout() -> u64 {
+    10
 }
 
+/// A command deciding whether a value of an argument is acceptable.
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+pub struct Validator {
+    pub command: Vec<String>,
+    /// How long, in seconds, to wait for the command before rejecting the value.
+    #[serde(default="default_validator_timeout")]
+    pub timeout: u64,
+    /// How long, in seconds, to remember the verdict for a value. Not at all by default.
+    pub cache_ttl: Option<u64>,
+}
+
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+pub struct RedactRule {
+    pub pattern: Pattern,
+    /// May refer to the pattern's capture groups, e.g. `$1`.
+    #[serde(default="default_replacement")]
+    pub replacement: String,
+}
+
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
 pub struct TaskConfig {
     pub command: Vec<String>,
+    /// Run by the preview endpoint instead of the command, with the same arguments.
+    pub dry_run_command: Option<Vec<String>>,
     #[serde(default="default_buffered")]
     pub buffered: bool,
     #[serde(default="default_meta")]
@@ -52,33 +514,458 @@ pub struct TaskConfig {
     #[serde(default)]
     pub headers: HashMap<String, String>,
     #[serde(default)]
-    pub arguments: Vec<Argument>
+    pub arguments: Vec<Argument>,
+    /// Maps environment variable names to the names of secrets exposed in them.
+    #[serde(default)]
+    pub secrets: HashMap<String, String>,
+    #[serde(default)]
+    pub redact: Vec<RedactRule>,
+    /// Names permission lists can refer to this task by, as `tag:NAME`.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Whether a run has to be approved by another user before it starts.
+    #[serde(default)]
+    pub requires_approval: bool,
+    /// How long a run waits for approval, in seconds.
+    #[serde(default="default_approval_timeout")]
+    pub approval_timeout: u64,
 }
 
+/// Limits the values a user can give an argument of a task they can run.
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
-pub struct User {
+pub struct Restriction {
+    /// The allowed values, any if empty.
     #[serde(default)]
-    pub can_run: Vec<String>,
+    pub values: Vec<String>,
+    /// A regular expression allowed values have to match as a whole.
+    pub pattern: Option<Pattern>,
+}
+
+impl Restriction {
+    pub fn allows(&self, value: &str) -> bool {
+        (self.values.is_empty() || self.values.iter().any(|allowed| allowed == value))
+            && self.pattern.as_ref().map_or(true, |pattern| pattern.matches_whole(value))
+    }
+}
+
+/// An API token, as stored in the configuration or in the token file.
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+pub struct Token {
+    pub name: String,
+    /// The SHA-256 hash of the token, hex-encoded.
+    pub hash: String,
+    /// The user, or service account, whose permissions the token has.
+    pub user: String,
+    pub expires: Option<DateTime<Utc>>,
+    /// Limits the token to some of its user's tasks, in the same format as permission lists.
+    pub tasks: Option<Vec<TaskEntry>>,
+}
+
+/// The restrictions that apply to a user for one task, by argument name.
+///
+/// A value has to be allowed by all of them, whether they come from the user or their groups.
+#[derive(Debug, Clone, Default)]
+pub struct Restrictions(HashMap<String, Vec<Restriction>>);
+
+impl Restrictions {
+    pub fn allows(&self, arg: &str, value: &str) -> bool {
+        self.0.get(arg).map_or(true, |restrictions| restrictions.iter().all(|restriction| restriction.allows(value)))
+    }
+}
+
+/// What a user, or a group, is allowed to do.
+///
+/// Each list can contain task names, glob patterns (e.g. `db_*`), and tags (e.g. `tag:readonly`).
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone, Default)]
+pub struct Permissions {
+    #[serde(default)]
+    pub can_run: Vec<TaskEntry>,
     #[serde(default)]
-    pub can_view_output: Vec<String>,
+    pub can_view_output: Vec<TaskEntry>,
     #[serde(default)]
-    pub can_view_status: Vec<String>,
+    pub can_view_status: Vec<TaskEntry>,
     #[serde(default)]
-    pub can_change_data: Vec<String>,
+    pub can_change_data: Vec<TaskEntry>,
+    #[serde(default)]
+    pub can_view_arguments: Vec<TaskEntry>,
+    /// Tasks whose runs the user can approve, when they require it.
+    #[serde(default)]
+    pub can_approve: Vec<TaskEntry>,
+    /// Restrictions of argument values, by task and argument name.
+    #[serde(default)]
+    pub restrictions: HashMap<String, HashMap<String, Restriction>>,
 }
 
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+pub struct User {
+    #[serde(flatten)]
+    pub permissions: Permissions,
+    /// Groups the user inherits permissions from.
+    #[serde(default)]
+    pub groups: Vec<String>,
+    /// Whether the user can only be authenticated with tokens.
+    #[serde(default)]
+    pub service_account: bool,
+    /// An argon2 or bcrypt hash of the password the user can log in with.
+    pub password: Option<String>,
+}
+
+/// An entry of a permission list: a task name, a glob pattern (e.g. `db_*`), or a tag (e.g. `tag:readonly`).
+#[derive(Debug, Clone)]
+pub enum TaskEntry {
+    Name(String),
+    Tag(String),
+    /// The glob as written, and the regular expression it's compiled to.
+    Glob(String, Box<Regex>),
+}
+
+impl TaskEntry {
+    pub fn new(entry: &str) -> Self {
+        if let Some(tag) = entry.strip_prefix("tag:") {
+            return TaskEntry::Tag(tag.to_owned())
+        }
+        if !entry.contains(['*', '?']) {
+            return TaskEntry::Name(entry.to_owned())
+        }
+        let pattern = regex::escape(entry).replace("\\*", ".*").replace("\\?", ".");
+        // An escaped glob is always a valid regular expression
+        TaskEntry::Glob(entry.to_owned(), Box::new(Regex::new(&format!("^{}$", pattern)).unwrap()))
+    }
+
+    pub fn as_str(&self) -> Cow<'_, str> {
+        match self {
+            TaskEntry::Name(name) => Cow::Borrowed(name),
+            TaskEntry::Tag(tag) => Cow::Owned(format!("tag:{}", tag)),
+            TaskEntry::Glob(glob, _) => Cow::Borrowed(glob),
+        }
+    }
+
+    /// Whether a task matches the entry.
+    pub fn permits(&self, name: &str, task: &TaskConfig) -> bool {
+        match self {
+            TaskEntry::Name(entry) => entry == name,
+            TaskEntry::Tag(tag) => task.tags.iter().any(|t| t == tag),
+            TaskEntry::Glob(_, regex) => regex.is_match(name),
+        }
+    }
+}
+
+impl PartialEq for TaskEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for TaskEntry {}
+
+impl Serialize for TaskEntry {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for TaskEntry {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(TaskEntry::new(&String::deserialize(deserializer)?))
+    }
+}
+
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
 pub struct Config {
     #[serde(default)]
     pub tasks: HashMap<String, TaskConfig>,
     #[serde(default)]
     pub users: HashMap<String, User>,
+    #[serde(default)]
+    pub groups: HashMap<String, Permissions>,
+    pub auth: Option<AuthConfig>,
+    pub proxy: Option<ProxyConfig>,
+    pub tls: Option<TlsConfig>,
+    pub audit: Option<AuditConfig>,
+    pub auth_mode: Option<AuthMode>,
+    /// Permissions of everyone who hasn't authenticated, when authentication is optional.
+    pub anonymous: Option<Permissions>,
+    /// Permissions of everyone who has authenticated, even if they aren't configured.
+    pub default_user: Option<Permissions>,
+    #[serde(default)]
+    pub tokens: Vec<Token>,
+    /// Where tokens created with the API are stored.
+    pub token_file: Option<String>,
     pub heartbeat: Option<u64>,
+    #[serde(default)]
+    pub secrets: HashMap<String, SecretSource>,
 }
 
 impl Config {
-    pub fn read(path: &str) -> Self {
-        let f = File::open(path).unwrap();
-        serde_yaml::from_reader(f).unwrap()
+    pub fn read(path: &str) -> anyhow::Result<Self> {
+        let f = File::open(path).with_context(|| format!("Cannot open {}", path))?;
+        let config: Self = serde_yaml::from_reader(f).with_context(|| format!("Cannot parse {}", path))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Checks references between parts of the configuration.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        for (name, task) in &self.tasks {
+            for command in std::iter::once(&task.command).chain(task.dry_run_command.iter()) {
+                let segments = cmdline::variables(command).with_context(|| format!("Invalid command of task {}", name))?;
+                for (segment, variables) in command.iter().zip(segments) {
+                    for variable in &variables {
+                        if !task.arguments.iter().any(|arg| &arg.name == variable) && !cmdline::BUILTIN_VARIABLES.contains(&&variable[..]) {
+                            bail!("Task {} refers to an undefined argument `{}` in its command. Use `$$` if you meant a literal `$`.", name, variable);
+                        }
+                    }
+                    if task.arguments.iter().filter(|arg| arg.multiple && variables.contains(&arg.name)).count() > 1 {
+                        bail!("Segment `{}` of task {} refers to more than one argument with multiple values", segment, name);
+                    }
+                }
+            }
+            if task.requires_approval && self.auth_mode() == AuthMode::Disabled {
+                bail!("Task {} requires approval, which needs users to authenticate", name);
+            }
+            for arg in &task.arguments {
+                if arg.datatype == ArgumentType::Enum && arg.values.is_empty() && arg.enum_file.is_none() && arg.enum_source.is_none() {
+                    bail!("Argument {} of task {} is an enum without values, an enum_file, or an enum_source", arg.name, name);
+                }
+                if let Some(ref data_source) = arg.data_source {
+                    if !self.tasks.contains_key(&data_source.task) {
+                        bail!("Argument {} of task {} has a data_source in an undefined task {}", arg.name, name, data_source.task);
+                    }
+                    if arg.datatype == ArgumentType::File {
+                        bail!("Argument {} of task {} is a file, it can't have a data_source", arg.name, name);
+                    }
+                }
+                if let Some(ref validator) = arg.validator {
+                    if validator.command.is_empty() {
+                        bail!("Argument {} of task {} has a validator with an empty command", arg.name, name);
+                    }
+                }
+                if let Some(ref source) = arg.enum_source {
+                    match self.tasks.get(source) {
+                        None => bail!("Argument {} of task {} has an undefined enum_source {}", arg.name, name, source),
+                        // Anyone who can run this task would run the source without anyone's approval
+                        Some(source_task) if source_task.requires_approval => {
+                            bail!("Argument {} of task {} has an enum_source {}, which requires approval", arg.name, name, source)
+                        },
+                        Some(_) => {}
+                    }
+                }
+                if !arg.depends_on.is_empty() {
+                    let source = match arg.enum_source {
+                        Some(ref source) => &self.tasks[source],
+                        None => bail!("Argument {} of task {} depends on other arguments, but has no enum_source", arg.name, name)
+                    };
+                    let earlier = task.arguments.iter().take_while(|earlier| earlier.name != arg.name);
+                    let earlier: Vec<_> = earlier.map(|earlier| &earlier.name).collect();
+                    for dependency in &arg.depends_on {
+                        if !earlier.contains(&dependency) {
+                            bail!("Argument {} of task {} depends on {}, which isn't an argument defined before it", arg.name, name, dependency);
+                        }
+                        if !source.arguments.iter().any(|source_arg| &source_arg.name == dependency) {
+                            bail!("Argument {} of task {} depends on {}, which isn't an argument of its enum_source", arg.name, name, dependency);
+                        }
+                    }
+                }
+            }
+            for secret in task.secrets.values() {
+                if !self.secrets.contains_key(secret) {
+                    bail!("Task {} refers to an undefined secret {}", name, secret);
+                }
+            }
+        }
+        if let Some(ref auth) = self.auth {
+            if auth.gitlab.is_some() && auth.oidc.is_some() {
+                bail!("Only one of auth.gitlab and auth.oidc can be set");
+            }
+            if auth.session_key.as_ref().map_or(false, |key| key.len() < 32) {
+                bail!("auth.session_key has to be at least 32 bytes long");
+            }
+            if auth.session_lifetime == 0 {
+                bail!("auth.session_lifetime has to be greater than 0");
+            }
+        }
+        if let Some(ref tls) = self.tls {
+            if tls.require_client_certificate && tls.client_ca.is_none() {
+                bail!("tls.require_client_certificate needs tls.client_ca to be set");
+            }
+        }
+        if let Some(ref audit) = self.audit {
+            if audit.file.is_none() && !audit.journald {
+                bail!("audit needs a file, journald, or both");
+            }
+            if audit.max_size == 0 {
+                bail!("audit.max_size has to be greater than 0");
+            }
+        }
+        if let Some(ref proxy) = self.proxy {
+            if proxy.secret_header.is_some() != proxy.secret.is_some() {
+                bail!("proxy.secret_header and proxy.secret have to be set together");
+            }
+        }
+        for (login, user) in &self.users {
+            for group in &user.groups {
+                if !self.groups.contains_key(group) {
+                    bail!("User {} is in an undefined group {}", login, group);
+                }
+            }
+            self.validate_restrictions(&format!("User {}", login), &user.permissions)?;
+            if user.password.as_ref().map_or(false, |hash| !passwords::is_supported(hash)) {
+                bail!("The password of user {} has to be an argon2 or bcrypt hash", login);
+            }
+        }
+        for (name, group) in &self.groups {
+            self.validate_restrictions(&format!("Group {}", name), group)?;
+        }
+        if let Some(ref anonymous) = self.anonymous {
+            if self.auth_mode() != AuthMode::Optional {
+                bail!("anonymous permissions are only used when auth_mode is Optional");
+            }
+            self.validate_restrictions("anonymous", anonymous)?;
+        }
+        if let Some(ref default_user) = self.default_user {
+            self.validate_restrictions("default_user", default_user)?;
+        }
+        for token in &self.tokens {
+            if !self.users.contains_key(&token.user) {
+                bail!("Token {} belongs to an undefined user {}", token.name, token.user);
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_restrictions(&self, owner: &str, permissions: &Permissions) -> anyhow::Result<()> {
+        for (name, restrictions) in &permissions.restrictions {
+            let task = match self.tasks.get(name) {
+                Some(task) => task,
+                None => bail!("{} has restrictions for an undefined task {}", owner, name)
+            };
+            for arg_name in restrictions.keys() {
+                match task.arguments.iter().find(|arg| &arg.name == arg_name) {
+                    Some(arg) if arg.datatype == ArgumentType::File => bail!("{} has restrictions for argument {} of task {}, but files can't be restricted", owner, arg_name, name),
+                    Some(_) => {},
+                    None => bail!("{} has restrictions for an undefined argument {} of task {}", owner, arg_name, name)
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether, and how, users authenticate.
+    ///
+    /// Unless it's set, authentication is required as soon as anyone could be let in.
+    pub fn auth_mode(&self) -> AuthMode {
+        match self.auth_mode {
+            Some(mode) => mode,
+            None if !self.users.is_empty() || !self.groups.is_empty() || self.auth.is_some() || self.default_user.is_some() => AuthMode::Required,
+            None => AuthMode::Disabled
+        }
+    }
+
+    /// Whether users can log in with a password.
+    pub fn has_passwords(&self) -> bool {
+        self.users.values().any(|user| user.password.is_some())
+            || self.auth.as_ref().map_or(false, |auth| auth.password_file.is_some())
+    }
+
+    /// How long a login lasts, in seconds.
+    pub fn session_lifetime(&self) -> u64 {
+        self.auth.as_ref().map(|auth| auth.session_lifetime).unwrap_or_else(default_session_lifetime)
+    }
+
+    /// Whether an identity is allowed in, by being a known user, or a member of a known group,
+    /// or by there being permissions for everyone who has authenticated.
+    pub fn knows(&self, identity: &Identity) -> bool {
+        self.default_user.is_some()
+            || self.users.contains_key(&identity.login)
+            || identity.groups.iter().any(|group| self.groups.contains_key(group))
+    }
+
+    /// Returns the permissions of a user, followed by those of their groups, and the default ones.
+    ///
+    /// Groups can come from both the configuration, and the identity provider.
+    fn permissions(&self, identity: &Identity) -> Vec<&Permissions> {
+        if identity.anonymous {
+            return self.anonymous.iter().collect()
+        }
+        let user = self.users.get(&identity.login);
+        let configured = user.into_iter().flat_map(|user| user.groups.iter());
+        user.map(|user| &user.permissions).into_iter()
+            .chain(configured.chain(identity.groups.iter()).filter_map(|group| self.groups.get(group)))
+            .chain(self.default_user.iter())
+            .collect()
+    }
+
+    /// Returns the names of tasks a user has a permission for, directly or through a group.
+    pub fn permitted_tasks(&self, identity: &Identity, list: impl Fn(&Permissions) -> &Vec<TaskEntry>) -> HashSet<String> {
+        let entries: Vec<&TaskEntry> = self.permissions(identity).into_iter().flat_map(list).collect();
+        self.tasks.iter()
+            .filter(|(name, task)| entries.iter().any(|entry| entry.permits(name, task)))
+            .filter(|(name, task)| identity.scope.as_ref().map_or(true, |scope| scope.iter().any(|entry| entry.permits(name, task))))
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// Returns the restrictions of a task's arguments that apply to a user.
+    pub fn restrictions(&self, identity: Option<&Identity>, task: &str) -> Restrictions {
+        let mut restrictions = Restrictions::default();
+        for permissions in identity.map(|identity| self.permissions(identity)).unwrap_or_default() {
+            for (arg, restriction) in permissions.restrictions.get(task).into_iter().flatten() {
+                restrictions.0.entry(arg.clone()).or_default().push(restriction.clone());
+            }
+        }
+        restrictions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cidr(cidr: &str) -> Result<Cidr, serde_yaml::Error> {
+        serde_yaml::from_str(&format!("'{}'", cidr))
+    }
+
+    fn contains(network: &str, address: &str) -> bool {
+        cidr(network).unwrap().contains(address.parse().unwrap())
+    }
+
+    #[test]
+    fn ipv4_networks() {
+        assert!(contains("10.1.0.0/16", "10.1.255.3"));
+        assert!(!contains("10.1.0.0/16", "10.2.0.1"));
+        assert!(contains("0.0.0.0/0", "192.0.2.1"));
+        assert!(contains("192.0.2.1/32", "192.0.2.1"));
+        assert!(!contains("192.0.2.1/32", "192.0.2.2"));
+        // Without a prefix, it's a single address
+        assert!(contains("192.0.2.1", "192.0.2.1"));
+        assert!(!contains("192.0.2.1", "192.0.2.0"));
+    }
+
+    #[test]
+    fn ipv6_networks() {
+        assert!(contains("2001:db8::/32", "2001:db8:1::1"));
+        assert!(!contains("2001:db8::/32", "2001:db9::1"));
+        assert!(contains("::/0", "2001:db8::1"));
+        assert!(contains("::1/128", "::1"));
+        assert!(!contains("::1", "::2"));
+    }
+
+    #[test]
+    fn mapped_ipv4_peers() {
+        assert!(contains("10.0.0.0/8", "::ffff:10.1.2.3"));
+        assert!(!contains("10.0.0.0/8", "::ffff:11.1.2.3"));
+        assert!(contains("127.0.0.1/32", "::ffff:127.0.0.1"));
+        // An IPv4 network doesn't contain IPv6 addresses, and vice versa
+        assert!(!contains("0.0.0.0/0", "2001:db8::1"));
+        assert!(!contains("2001:db8::/32", "10.1.2.3"));
+    }
+
+    #[test]
+    fn invalid_networks() {
+        assert!(cidr("10.0.0.0/33").is_err());
+        assert!(cidr("::/129").is_err());
+        assert!(cidr("10.0.0.0/").is_err());
+        assert!(cidr("10.0.0/8").is_err());
+        assert!(cidr("10.0.0.0/8").is_ok());
     }
 }
@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Variables that are always available in commands, besides the task's arguments.
+pub const BUILTIN_VARIABLES: &[&str] = &["taru_user"];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Piece {
+    Literal(String),
+    Variable(String),
+}
+
+/// The values a variable expands to in a command.
+#[derive(Debug, Clone, Default)]
+pub struct Variable {
+    pub values: Vec<String>,
+    /// A separate segment put before every segment referring to the variable.
+    pub prefix: Option<String>,
+}
+
+impl From<String> for Variable {
+    fn from(value: String) -> Self {
+        Variable { values: vec![value], prefix: None }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum TemplateError {
+    #[error("unterminated `${{` in `{0}`")]
+    Unterminated(String),
+    #[error("`{1}` in `{0}` is not a valid variable name")]
+    InvalidName(String, String),
+    #[error("`{0}` refers to more than one variable with multiple values")]
+    MultipleLists(String),
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn is_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') && name.chars().all(is_name_char)
+}
+
+/// Splits a command segment into literal text and variable references.
+///
+/// Both `$name` and `${name}` refer to a variable, `$$` is a literal `$`,
+/// and a `$` followed by anything else is kept as is.
+pub fn parse(segment: &str) -> Result<Vec<Piece>, TemplateError> {
+    let mut pieces = vec![];
+    let mut literal = String::new();
+    let mut rest = segment;
+    while let Some(pos) = rest.find('$') {
+        literal.push_str(&rest[..pos]);
+        rest = &rest[pos + 1..];
+        let name = if rest.starts_with('$') {
+            literal.push('$');
+            rest = &rest[1..];
+            continue
+        } else if rest.starts_with('{') {
+            let end = rest.find('}').ok_or_else(|| TemplateError::Unterminated(segment.to_owned()))?;
+            let name = &rest[1..end];
+            if !is_name(name) {
+                return Err(TemplateError::InvalidName(segment.to_owned(), name.to_owned()));
+            }
+            rest = &rest[end + 1..];
+            name
+        } else {
+            let end = rest.find(|c| !is_name_char(c)).unwrap_or(rest.len());
+            if !is_name(&rest[..end]) {
+                literal.push('$');
+                continue
+            }
+            let name = &rest[..end];
+            rest = &rest[end..];
+            name
+        };
+        if !literal.is_empty() {
+            pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+        }
+        pieces.push(Piece::Variable(name.to_owned()));
+    }
+    literal.push_str(rest);
+    if !literal.is_empty() {
+        pieces.push(Piece::Literal(literal));
+    }
+    Ok(pieces)
+}
+
+/// Returns the names of variables each segment of a command refers to.
+pub fn variables(command: &[String]) -> Result<Vec<Vec<String>>, TemplateError> {
+    let mut segments = vec![];
+    for segment in command {
+        let mut names = vec![];
+        for piece in parse(segment)? {
+            if let Piece::Variable(name) = piece {
+                names.push(name);
+            }
+        }
+        segments.push(names);
+    }
+    Ok(segments)
+}
+
+/// Substitutes variables in a command.
+///
+/// A segment referring to a variable that has no value is dropped entirely.
+/// A segment referring to a variable with multiple values is repeated for each of them.
+pub fn render(command: &[String], variables: &HashMap<String, Variable>) -> Result<Vec<String>, TemplateError> {
+    let mut cmdline = vec![];
+    'segments: for segment in command {
+        let pieces = parse(segment)?;
+        let mut referenced = vec![];
+        for piece in &pieces {
+            if let Piece::Variable(name) = piece {
+                match variables.get(name) {
+                    Some(variable) if !variable.values.is_empty() => referenced.push((name, variable)),
+                    _ => continue 'segments
+                }
+            }
+        }
+
+        let mut lists = referenced.iter().filter(|(_, variable)| variable.values.len() > 1);
+        let list = lists.next().map(|(name, _)| *name);
+        if lists.next().is_some() {
+            return Err(TemplateError::MultipleLists(segment.clone()));
+        }
+        let prefix = referenced.iter().find_map(|(_, variable)| variable.prefix.as_ref());
+        let count = list.map(|name| variables[name].values.len()).unwrap_or(1);
+
+        for i in 0..count {
+            if let Some(prefix) = prefix {
+                cmdline.push(prefix.clone());
+            }
+            let mut rendered = String::new();
+            for piece in &pieces {
+                match piece {
+                    Piece::Literal(text) => rendered.push_str(text),
+                    Piece::Variable(name) => {
+                        let values = &variables[name].values;
+                        rendered.push_str(&values[if Some(name) == list { i } else { 0 }]);
+                    }
+                }
+            }
+            cmdline.push(rendered);
+        }
+    }
+    Ok(cmdline)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn literal(text: &str) -> Piece {
+        Piece::Literal(text.to_owned())
+    }
+
+    fn variable(name: &str) -> Piece {
+        Piece::Variable(name.to_owned())
+    }
+
+    fn command(segments: &[&str]) -> Vec<String> {
+        segments.iter().map(|segment| segment.to_string()).collect()
+    }
+
+    fn values(values: &[&str], prefix: Option<&str>) -> Variable {
+        Variable { values: values.iter().map(|value| value.to_string()).collect(), prefix: prefix.map(String::from) }
+    }
+
+    #[test]
+    fn variables_and_literals() {
+        assert_eq!(parse("--name=$name").unwrap(), vec![literal("--name="), variable("name")]);
+        assert_eq!(parse("${dir}_old/$file.txt").unwrap(), vec![variable("dir"), literal("_old/"), variable("file"), literal(".txt")]);
+        assert_eq!(parse("costs $$5").unwrap(), vec![literal("costs $5")]);
+        assert_eq!(parse("$$name").unwrap(), vec![literal("$name")]);
+    }
+
+    #[test]
+    fn dollars_not_followed_by_a_name_are_kept() {
+        assert_eq!(parse("awk '{print $1}'").unwrap(), vec![literal("awk '{print $1}'")]);
+        assert_eq!(parse("total$").unwrap(), vec![literal("total$")]);
+        assert_eq!(parse("$").unwrap(), vec![literal("$")]);
+    }
+
+    #[test]
+    fn invalid_references() {
+        assert!(matches!(parse("${name"), Err(TemplateError::Unterminated(_))));
+        assert!(matches!(parse("${1}"), Err(TemplateError::InvalidName(_, _))));
+        assert!(matches!(parse("${}"), Err(TemplateError::InvalidName(_, _))));
+    }
+
+    #[test]
+    fn segments_with_empty_variables_are_dropped() {
+        let mut variables = HashMap::new();
+        variables.insert("name".to_owned(), values(&["web"], None));
+        variables.insert("tag".to_owned(), values(&[], None));
+        let rendered = render(&command(&["deploy", "--name=$name", "--tag=${tag}", "$missing"]), &variables).unwrap();
+        assert_eq!(rendered, command(&["deploy", "--name=web"]));
+    }
+
+    #[test]
+    fn lists_are_expanded_with_their_prefix() {
+        let mut variables = HashMap::new();
+        variables.insert("host".to_owned(), values(&["a", "b"], Some("-h")));
+        variables.insert("port".to_owned(), values(&["22"], None));
+        let rendered = render(&command(&["ssh", "$host:$port", "$$"]), &variables).unwrap();
+        assert_eq!(rendered, command(&["ssh", "-h", "a:22", "-h", "b:22", "$"]));
+
+        variables.insert("port".to_owned(), values(&["22", "2222"], None));
+        assert!(matches!(render(&command(&["$host:$port"]), &variables), Err(TemplateError::MultipleLists(_))));
+    }
+}
@@ -4,13 +4,18 @@ use serde_json::json;
 use bytes::{BytesMut, BufMut, Bytes};
 use std::collections::HashMap;
 
+use crate::task::{ArgumentValue, Approval, Resolution};
+
 #[derive(Debug, Serialize, Clone)]
 pub enum Event {
     Ping,
-    Started(String, HashMap<String, String>),
+    Started(String, HashMap<String, ArgumentValue>),
     Finished(String, Option<i32>),
     TaskData(String, String, String),
     UpdateConfig,
+    ApprovalRequested(String, Approval),
+    /// The task, the approval's id, what has become of it, and who has decided.
+    ApprovalResolved(String, String, Resolution, Option<String>),
 }
 
 impl Event {
@@ -20,7 +25,9 @@ impl Event {
             Self::Started(_, _) => "started",
             Self::Finished(_, _) => "finished",
             Self::TaskData(_, _, _) => "task_data",
-            Self::UpdateConfig => "update_config"
+            Self::UpdateConfig => "update_config",
+            Self::ApprovalRequested(_, _) => "approval_requested",
+            Self::ApprovalResolved(_, _, _, _) => "approval_resolved",
         }
     }
 
@@ -35,6 +42,12 @@ impl Event {
             }, Self::Finished(task, exit_code) => {
                 data.put(serde_json::to_vec(&json!({"task": task, "exit_code": exit_code})).unwrap().as_slice());
             },
+            Self::ApprovalRequested(task, approval) => {
+                data.put(serde_json::to_vec(&json!({"task": task, "approval": approval})).unwrap().as_slice());
+            },
+            Self::ApprovalResolved(task, id, resolution, by) => {
+                data.put(serde_json::to_vec(&json!({"task": task, "id": id, "resolution": resolution, "by": by})).unwrap().as_slice());
+            },
             _ => {}
         };
         data.put(&b"\n\n"[..]);
@@ -0,0 +1,281 @@
+use std::collections::HashMap;
+use std::io::Write;
+use actix_web::{web, HttpRequest, HttpResponse, FromRequest};
+use actix_multipart::Multipart;
+use futures::StreamExt;
+use tempfile::NamedTempFile;
+use serde_json::Value;
+
+use crate::cfg::{TaskConfig, Argument, ArgumentType};
+
+/// The default limit of an uploaded file's size.
+const DEFAULT_MAX_SIZE: u64 = 10 * 1024 * 1024;
+/// The limit of a single text field in a multipart body.
+const MAX_FIELD_SIZE: usize = 65536;
+/// The limit of the number of fields in a multipart body, files included.
+const MAX_FIELDS: usize = 1000;
+/// The limit of the number of files uploaded in one request.
+const MAX_FILES: usize = 20;
+/// The default limit of the size of all files uploaded in one request, raised to any argument's `max_size` above it.
+const DEFAULT_MAX_TOTAL_SIZE: u64 = 100 * 1024 * 1024;
+
+/// A file uploaded for a `File` argument. It's removed when this is dropped.
+#[derive(Debug)]
+pub struct Upload {
+    pub file: NamedTempFile,
+    /// The name the client has given the file.
+    pub filename: String,
+}
+
+impl Upload {
+    pub fn path(&self) -> String {
+        self.file.path().to_string_lossy().into_owned()
+    }
+}
+
+/// Argument values sent by a client, not validated yet.
+#[derive(Debug, Default)]
+pub struct RawArguments {
+    pub values: HashMap<String, Vec<String>>,
+    pub uploads: HashMap<String, Vec<Upload>>,
+}
+
+impl RawArguments {
+    fn from_pairs(pairs: Vec<(String, String)>) -> Self {
+        let mut arguments = RawArguments::default();
+        arguments.extend(pairs);
+        arguments
+    }
+
+    /// Removes and returns the values given for an argument.
+    ///
+    /// Values of a `File` are the paths of its uploads, so that a client can't pass an arbitrary path instead.
+    pub fn take(&mut self, arg: &Argument) -> Vec<String> {
+        if arg.datatype == ArgumentType::File {
+            self.uploads.get(&arg.name).map(|uploads| uploads.iter().map(Upload::path).collect()).unwrap_or_default()
+        } else {
+            self.values.remove(&arg.name).unwrap_or_default()
+        }
+    }
+
+    fn extend(&mut self, pairs: Vec<(String, String)>) {
+        for (name, value) in pairs {
+            self.values.entry(name).or_default().push(value);
+        }
+    }
+}
+
+pub fn read_query(req: &HttpRequest) -> Result<RawArguments, HttpResponse> {
+    match web::Query::<Vec<(String, String)>>::from_query(req.query_string()) {
+        Ok(query) => Ok(RawArguments::from_pairs(query.into_inner())),
+        Err(e) => Err(HttpResponse::BadRequest().body(format!("Invalid query string: {}", e)))
+    }
+}
+
+/// Reads the arguments sent in the query string and the request body.
+pub async fn read_arguments(req: &HttpRequest, body: web::Payload, task: &TaskConfig) -> Result<RawArguments, HttpResponse> {
+    let mut arguments = read_query(req)?;
+
+    let content_type = req.headers().get("content-type").and_then(|h| h.to_str().ok()).unwrap_or_default();
+    if content_type.starts_with("application/x-www-form-urlencoded") {
+        match web::Form::<Vec<(String, String)>>::from_request(req, &mut body.into_inner()).await {
+            Ok(form) => arguments.extend(form.into_inner()),
+            Err(e) => return Err(HttpResponse::BadRequest().body(format!("Invalid form data: {}", e)))
+        }
+    } else if content_type.starts_with("application/json") {
+        match web::Json::<Value>::from_request(req, &mut body.into_inner()).await {
+            Ok(json) => arguments.extend(json_values(task, json.into_inner())?),
+            Err(e) => return Err(HttpResponse::BadRequest().body(format!("Invalid JSON: {}", e)))
+        }
+    } else if content_type.starts_with("multipart/form-data") {
+        read_multipart(req, body, task, &mut arguments).await?;
+    }
+
+    Ok(arguments)
+}
+
+fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "a list",
+        Value::Object(_) => "an object",
+    }
+}
+
+/// Converts a JSON value to the text form other arguments come in.
+///
+/// Strings are accepted for every datatype, since they're validated later anyway,
+/// but numbers and booleans only for the datatypes they make sense for.
+fn json_value(arg: &Argument, value: Value) -> Result<String, HttpResponse> {
+    match (&arg.datatype, value) {
+        (_, Value::String(value)) => Ok(value),
+        (ArgumentType::Int, Value::Number(number)) if number.is_i64() => Ok(number.to_string()),
+        (ArgumentType::Int, Value::Number(number)) => Err(HttpResponse::BadRequest().body(format!("Argument {} has to be a whole number, not {}.", arg.name, number))),
+        (ArgumentType::Float, Value::Number(number)) => Ok(number.to_string()),
+        (ArgumentType::Bool, Value::Bool(value)) => Ok(value.to_string()),
+        (datatype, value) => Err(HttpResponse::BadRequest().body(format!("Argument {} is {:?}, it can't be {}.", arg.name, datatype, json_type(&value))))
+    }
+}
+
+fn json_values(task: &TaskConfig, body: Value) -> Result<Vec<(String, String)>, HttpResponse> {
+    let object = match body {
+        Value::Object(object) => object,
+        other => return Err(HttpResponse::BadRequest().body(format!("The request body has to be an object of arguments, not {}.", json_type(&other))))
+    };
+
+    let mut pairs = vec![];
+    for (name, value) in object {
+        let arg = match task.arguments.iter().find(|arg| arg.name == name) {
+            Some(arg) => arg,
+            None => return Err(HttpResponse::BadRequest().body(format!("Unknown argument {}.", name)))
+        };
+        if arg.datatype == ArgumentType::File {
+            return Err(HttpResponse::BadRequest().body(format!("Argument {} is a file, it has to be uploaded in a multipart body.", name)));
+        }
+        match value {
+            Value::Null => {},
+            Value::Array(values) if arg.multiple => {
+                for value in values {
+                    pairs.push((name.clone(), json_value(arg, value)?));
+                }
+            },
+            Value::Array(_) => return Err(HttpResponse::BadRequest().body(format!("Argument {} takes a single value, not a list.", name))),
+            value => pairs.push((name.clone(), json_value(arg, value)?))
+        }
+    }
+    Ok(pairs)
+}
+
+async fn read_multipart(req: &HttpRequest, body: web::Payload, task: &TaskConfig, arguments: &mut RawArguments) -> Result<(), HttpResponse> {
+    let bad_request = |e| HttpResponse::BadRequest().body(format!("Invalid multipart data: {}", e));
+    let mut multipart = Multipart::new(req.headers(), body);
+    let max_total_size = task.arguments.iter().filter_map(|arg| arg.max_size).fold(DEFAULT_MAX_TOTAL_SIZE, u64::max);
+    let (mut fields, mut files, mut total_size) = (0, 0, 0u64);
+    while let Some(field) = multipart.next().await {
+        let mut field = field.map_err(bad_request)?;
+        fields += 1;
+        if fields > MAX_FIELDS {
+            return Err(HttpResponse::PayloadTooLarge().body(format!("A request can have at most {} fields.", MAX_FIELDS)));
+        }
+        let disposition = match field.content_disposition() {
+            Some(disposition) => disposition,
+            None => continue
+        };
+        let name = match disposition.get_name() {
+            Some(name) => name.to_owned(),
+            None => continue
+        };
+        let file_arg = task.arguments.iter().find(|arg| arg.name == name && arg.datatype == ArgumentType::File);
+
+        if let (Some(arg), Some(filename)) = (file_arg, disposition.get_filename()) {
+            files += 1;
+            if files > MAX_FILES {
+                return Err(HttpResponse::PayloadTooLarge().body(format!("At most {} files can be uploaded at once.", MAX_FILES)));
+            }
+            let content_type = field.content_type().essence_str().to_owned();
+            if !arg.content_types.is_empty() && !arg.content_types.contains(&content_type) {
+                return Err(HttpResponse::UnsupportedMediaType().body(format!("Argument {} has to be one of: {}, not {}.", arg.name, arg.content_types.join(", "), content_type)));
+            }
+
+            // Keep the extension, some commands care about it
+            let extension = filename.rsplit('.').next().filter(|ext| ext.len() < filename.len() && ext.chars().all(|c| c.is_ascii_alphanumeric()));
+            let suffix = extension.map(|ext| format!(".{}", ext)).unwrap_or_default();
+            let server_error = |e| HttpResponse::InternalServerError().body(format!("Cannot store the uploaded file: {}", e));
+            let mut file = tempfile::Builder::new().prefix("taru-upload-").suffix(&suffix).tempfile().map_err(server_error)?;
+
+            let max_size = arg.max_size.unwrap_or(DEFAULT_MAX_SIZE);
+            let mut size = 0u64;
+            while let Some(chunk) = field.next().await {
+                let chunk = chunk.map_err(bad_request)?;
+                size += chunk.len() as u64;
+                total_size += chunk.len() as u64;
+                if size > max_size {
+                    return Err(HttpResponse::PayloadTooLarge().body(format!("Argument {} can be at most {} bytes long.", arg.name, max_size)));
+                }
+                if total_size > max_total_size {
+                    return Err(HttpResponse::PayloadTooLarge().body(format!("The uploaded files can be at most {} bytes long in total.", max_total_size)));
+                }
+                file.write_all(&chunk).map_err(server_error)?;
+            }
+            file.flush().map_err(server_error)?;
+            arguments.uploads.entry(name).or_default().push(Upload { file, filename: filename.to_owned() });
+        } else {
+            let mut value = Vec::new();
+            while let Some(chunk) = field.next().await {
+                value.extend_from_slice(&chunk.map_err(bad_request)?);
+                if value.len() > MAX_FIELD_SIZE {
+                    return Err(HttpResponse::PayloadTooLarge().body(format!("Field {} is too long.", name)));
+                }
+            }
+            match String::from_utf8(value) {
+                Ok(value) => arguments.values.entry(name).or_default().push(value),
+                Err(_) => return Err(HttpResponse::BadRequest().body(format!("Field {} is not valid UTF-8.", name)))
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::http::StatusCode;
+    use serde_json::json;
+
+    fn task() -> TaskConfig {
+        serde_yaml::from_str("
+            command: [deploy]
+            arguments:
+            - {name: count, datatype: Int}
+            - {name: ratio, datatype: Float}
+            - {name: force, datatype: Bool}
+            - {name: hosts, datatype: String, multiple: true}
+            - {name: name, datatype: String}
+            - {name: upload, datatype: File}
+        ").unwrap()
+    }
+
+    fn pairs(body: Value) -> Vec<(String, String)> {
+        json_values(&task(), body).unwrap()
+    }
+
+    fn rejected(body: Value) -> bool {
+        json_values(&task(), body).err().map(|response| response.status()) == Some(StatusCode::BAD_REQUEST)
+    }
+
+    #[test]
+    fn values_of_each_datatype() {
+        let pair = |name: &str, value: &str| (name.to_owned(), value.to_owned());
+        assert_eq!(pairs(json!({"count": 3})), vec![pair("count", "3")]);
+        assert_eq!(pairs(json!({"ratio": 0.5})), vec![pair("ratio", "0.5")]);
+        assert_eq!(pairs(json!({"force": true})), vec![pair("force", "true")]);
+        // Strings are left for the argument's validation
+        assert_eq!(pairs(json!({"count": "3"})), vec![pair("count", "3")]);
+        assert_eq!(pairs(json!({"hosts": ["a", "b"]})), vec![pair("hosts", "a"), pair("hosts", "b")]);
+        assert_eq!(pairs(json!({"name": null})), vec![]);
+    }
+
+    #[test]
+    fn ints_have_to_be_whole() {
+        assert!(rejected(json!({"count": 1.5})));
+        assert!(rejected(json!({"count": 1e3})));
+        assert!(rejected(json!({"count": true})));
+    }
+
+    #[test]
+    fn lists_only_for_multiple_arguments() {
+        assert!(rejected(json!({"name": ["a", "b"]})));
+        assert!(rejected(json!({"hosts": [["a"]]})));
+        assert!(rejected(json!({"force": "yes", "name": {"first": "a"}})));
+    }
+
+    #[test]
+    fn unknown_and_file_arguments() {
+        assert!(rejected(json!({"unknown": "a"})));
+        assert!(rejected(json!({"upload": "/etc/passwd"})));
+        assert!(rejected(json!(["count", 3])));
+    }
+}
@@ -1,8 +1,9 @@
 use actix_web::{
     web, App, HttpRequest, HttpServer, HttpResponse,
-    get, post, Scope, dev::{ServiceRequest, ServiceResponse},
-    error::Error as ActixError, FromRequest
+    get, post, Scope, dev::{ServiceRequest, ServiceResponse, AppConfig},
+    error::Error as ActixError, HttpMessage, cookie::SameSite
 };
+use actix_session::CookieSession;
 use serde::Deserialize;
 use http::StatusCode;
 use actix_files::Files;
@@ -15,7 +16,9 @@ use std::collections::{HashMap, HashSet};
 use std::pin::Pin;
 use parking_lot::RwLock;
 use std::sync::Arc;
-use actix_service::Service;
+use actix_service::{Service, map_config, apply_fn_factory};
+use actix_http::HttpService;
+use actix_server::Server;
 use tokio::signal::unix::{signal, SignalKind};
 use tokio::time::{self, Duration};
 use tokio::select;
@@ -23,16 +26,28 @@ use serde::Serialize;
 use serde_json;
 use libc::geteuid;
 use paste::paste;
+use rand::Rng;
 
 mod cfg;
 mod event;
 mod broadcast;
 mod task;
 mod app_state;
+mod secrets;
+mod redact;
+mod arguments;
+mod cmdline;
+mod input;
+mod auth;
+mod tokens;
+mod passwords;
+mod tls;
+mod audit;
 
 use app_state::AppState;
 use task::TaskOutput;
 use event::{Event, send_message};
+use audit::{Record, Outcome};
 
 #[derive(Debug, Serialize)]
 struct TaskData<'a> {
@@ -40,11 +55,15 @@ struct TaskData<'a> {
     meta: &'a serde_json::Value,
     data: HashMap<String, String>,
     arguments: &'a Vec<cfg::Argument>,
-    argument_values: HashMap<String, String>,
+    /// Choices of the enum arguments whose sources are ready, and don't depend on other arguments
+    options: HashMap<&'a str, Vec<cfg::Choice>>,
+    argument_values: HashMap<String, task::ArgumentValue>,
     state: &'static str,
     exit_code: Option<i32>,
     can_run: bool,
-    can_view_output: bool
+    can_view_output: bool,
+    pending_approval: Option<task::Approval>,
+    can_approve: bool
 }
 
 macro_rules! generate_perm_checks {
@@ -52,9 +71,8 @@ macro_rules! generate_perm_checks {
         paste! {
             fn [<get_ $name _tasks>](req: &HttpRequest) -> HashSet<String> {
                 let data: &web::Data<Arc<RwLock<AppState>>> = req.app_data().unwrap();
-                let login = req.headers().get("x-user").map(|h| h.to_str().unwrap());
-                match login {
-                    Some(login) => data.read().config.users.get(login).unwrap().[<can_ $name>].iter().map(String::from).collect(),
+                match auth::identity(req) {
+                    Some(identity) => data.read().config.permitted_tasks(&identity, |permissions| &permissions.[<can_ $name>]),
                     None => data.read().config.tasks.keys().map(String::from).collect()
                 }
             }
@@ -64,23 +82,57 @@ macro_rules! generate_perm_checks {
                 let task = req.match_info().get("task").unwrap();
                 [<get_ $name _tasks>](req).iter().find(|t| *t == &task).is_some()
             }
+
+        }
+    };
+    // For permissions guarding endpoints, whose denials are recorded in the audit log
+    ($name:ident, checked) => {
+        generate_perm_checks!($name);
+
+        paste! {
+            fn [<check_ $name>](req: &HttpRequest) -> bool {
+                [<can_ $name>](req) || permission_denied(req, concat!("can_", stringify!($name)))
+            }
         }
     }
 }
 
-generate_perm_checks!(view_status);
-generate_perm_checks!(view_output);
-generate_perm_checks!(run);
-generate_perm_checks!(change_data);
+/// Records that a user lacks a permission for the task in the request, and returns false.
+fn permission_denied(req: &HttpRequest, permission: &str) -> bool {
+    let task = req.match_info().get("task").unwrap();
+    audit::log(req, Record::request("permission_denied", Outcome::Denied, req).task(task).detail(permission));
+    false
+}
+
+generate_perm_checks!(view_status, checked);
+generate_perm_checks!(view_output, checked);
+generate_perm_checks!(run, checked);
+generate_perm_checks!(change_data, checked);
+generate_perm_checks!(view_arguments);
+generate_perm_checks!(approve, checked);
 
 #[get("/tasks")]
 async fn tasks(req: HttpRequest, data: web::Data<Arc<RwLock<AppState>>>) -> HttpResponse {
     let data = data.read();
     let can_run: HashSet<_> = get_run_tasks(&req);
     let can_view_output: HashSet<_> = get_view_output_tasks(&req);
+    let can_view_arguments: HashSet<_> = get_view_arguments_tasks(&req);
+    let can_approve: HashSet<_> = get_approve_tasks(&req);
     let tasks = get_view_status_tasks(&req);
+    let identity = auth::identity(&req);
     HttpResponse::Ok().json(
         tasks.iter().map(|name| {
+            let restrictions = data.config.restrictions(identity.as_ref(), name);
+            // Choices depending on other arguments can be loaded with /task/{task}/arguments/{argument}/options
+            let options = data.config.tasks[name].arguments.iter()
+                .filter(|arg| arg.datatype == cfg::ArgumentType::Enum && arg.depends_on.is_empty())
+                .filter_map(|arg| match arguments::static_choices(arg, &data) {
+                    Ok(Some(mut choices)) => {
+                        choices.retain(|choice| restrictions.allows(&arg.name, &choice.value));
+                        Some((&arg.name[..], choices))
+                    },
+                    _ => None
+                }).collect();
             let task = data.tasks.get(name).unwrap().read();
             (name, TaskData {
                 name: name,
@@ -92,90 +144,423 @@ async fn tasks(req: HttpRequest, data: web::Data<Arc<RwLock<AppState>>>) -> Http
                     task::TaskStatus::Finished(_) => "finished"
                 },
                 arguments: &data.config.tasks[name].arguments,
-                argument_values: task.arguments.clone(),
+                options,
+                argument_values: if can_view_arguments.contains(name) {
+                    task.arguments.clone()
+                } else {
+                    task::mask_sensitive(&data.config.tasks[name], &task.arguments)
+                },
                 exit_code: task.status.as_finished(),
                 can_run: can_run.contains(name),
-                can_view_output: can_view_output.contains(name)
+                can_view_output: can_view_output.contains(name),
+                pending_approval: task.pending.as_ref().map(|pending| if can_view_arguments.contains(name) {
+                    pending.approval.clone()
+                } else {
+                    pending.approval.masked(&data.config.tasks[name])
+                }),
+                can_approve: can_approve.contains(name)
             })
         }).collect::<HashMap<_, _>>()
     )
 }
 
-async fn run_task(req: &HttpRequest, data: &web::Data<Arc<RwLock<AppState>>>, params: &web::Path<(String,)>) -> Result<(), HttpResponse> {
-    if !can_run(&req) {
+fn current_user(req: &HttpRequest) -> Option<String> {
+    auth::identity(req).filter(|identity| !identity.anonymous).map(|identity| identity.login)
+}
+
+/// A run whose arguments have been validated, and whose command is ready to start.
+struct PreparedRun {
+    events: tokio::sync::broadcast::Sender<Event>,
+    task: cfg::TaskConfig,
+    state: Arc<RwLock<task::TaskState>>,
+    user: Option<String>,
+    /// The values to show users, with the names of uploaded files instead of their paths.
+    arguments: HashMap<String, task::ArgumentValue>,
+    variables: HashMap<String, cmdline::Variable>,
+    invocation: task::Invocation,
+}
+
+/// Validates the arguments of a run, and substitutes them in the task's command.
+/// Users have to be checked for the `run` permission first.
+async fn prepare_run(req: &HttpRequest, data: &web::Data<Arc<RwLock<AppState>>>, params: &web::Path<(String,)>, body: web::Payload) -> Result<PreparedRun, HttpResponse> {
+    let (events, task, state, secrets, restrictions) = {
+        let data = data.read();
+        (
+            data.events.clone(),
+            data.config.tasks.get(&params.0).unwrap().clone(),
+            data.tasks.get(&params.0).unwrap().clone(),
+            data.config.secrets.clone(),
+            data.config.restrictions(auth::identity(req).as_ref(), &params.0),
+        )
+    };
+    let mut input = input::read_arguments(req, body, &task).await?;
+
+    let mut ctx = arguments::Context::new(data.get_ref(), &params.0, current_user(req));
+    ctx.restrictions = restrictions;
+    let mut variables = HashMap::new();
+    let mut argument_values = HashMap::new();
+    for arg in &task.arguments {
+        let values = arguments::resolve(arg, input.take(arg), &ctx).await?;
+        // The paths of uploads don't mean anything to anyone, unlike their original names
+        let shown: Vec<String> = match input.uploads.get(&arg.name) {
+            Some(uploads) if arg.datatype == cfg::ArgumentType::File => uploads.iter().take(values.len()).map(|upload| upload.filename.clone()).collect(),
+            _ => values.clone()
+        };
+        if arg.multiple {
+            argument_values.insert(arg.name.clone(), task::ArgumentValue::Multiple(shown));
+        } else if let Some(value) = shown.into_iter().next() {
+            argument_values.insert(arg.name.clone(), task::ArgumentValue::Single(value));
+        }
+        ctx.values.insert(arg.name.clone(), values.clone());
+        variables.insert(arg.name.clone(), cmdline::Variable { values, prefix: arg.prefix.clone() });
+    }
+
+    if !variables.contains_key("taru_user") {
+        variables.insert("taru_user".to_owned(), ctx.user.clone().unwrap_or_default().into());
+    }
+    let mut invocation = match task::Invocation::prepare(&task, &secrets, &variables) {
+        Ok(invocation) => invocation,
+        Err(e) => return Err(HttpResponse::InternalServerError().body(format!("{:#}", e)))
+    };
+
+    invocation.uploads = input.uploads.drain().flat_map(|(_, uploads)| uploads).collect();
+    Ok(PreparedRun { events, task, state, user: ctx.user, arguments: argument_values, variables, invocation })
+}
+
+async fn run_task(req: &HttpRequest, data: &web::Data<Arc<RwLock<AppState>>>, params: &web::Path<(String,)>, body: web::Payload) -> Result<(), HttpResponse> {
+    if !check_run(req) {
         return Err(HttpResponse::NotFound().finish())
     }
-    let events = data.read().events.clone();
-    let data = data.read();
-    let ref task = data.config.tasks.get(&params.0).unwrap();
-    let state = data.tasks.get(&params.0).unwrap().clone();
+    let record = Record::request("run", Outcome::Success, req).task(&params.0);
+    // Validating the arguments can run commands and store uploads, which is pointless if the task can't start anyway
+    let state = data.read().tasks.get(&params.0).unwrap().clone();
     if state.read().status == task::TaskStatus::Running {
+        audit::log(req, record.status(StatusCode::CONFLICT));
+        return Err(HttpResponse::Conflict().body("The task is already running. Refusing to run two instances in parallel."));
+    }
+    let run = match prepare_run(req, data, params, body).await {
+        Ok(run) => run,
+        Err(response) => {
+            audit::log(req, record.status(response.status()));
+            return Err(response)
+        }
+    };
+    let record = record.arguments(task::mask_sensitive(&run.task, &run.arguments));
+    // It could have been started in the meantime
+    if run.state.read().status == task::TaskStatus::Running {
+        audit::log(req, record.status(StatusCode::CONFLICT));
+        return Err(HttpResponse::Conflict().body("The task is already running. Refusing to run two instances in parallel."));
+    }
+    if run.task.requires_approval {
+        return Err(request_approval(req, &params.0, run, record))
+    }
+    let PreparedRun { events, state, arguments, invocation, .. } = run;
+    state.write().arguments = arguments;
+    state.write().output = BytesMut::new();
+    if task::spawn_task(events, state, invocation).await.is_err() {
+        audit::log(req, record.status(StatusCode::CONFLICT));
         return Err(HttpResponse::Conflict().body("The task is already running. Refusing to run two instances in parallel."));
     }
-    let mut args = HashMap::new();
-    let params = web::Query::<HashMap<String, String>>::extract(req).await.unwrap().into_inner();
-    let post = if let Ok(payload) = web::Form::<HashMap<String, String>>::extract(req).await {
-        payload.into_inner()
-    } else { HashMap::new() };
 
-    for arg in &task.arguments {
-        if let Some(value) = params.get(&arg.name).or(post.get(&arg.name)) {
-            if arg.datatype == cfg::ArgumentType::Int && value.parse::<i32>().is_err() {
-                return Err(HttpResponse::BadRequest().body(format!("Argument {} has to be a number, but is `{}` instead.", arg.name, value)));
+    audit::log(req, record.status(StatusCode::OK));
+    Ok(())
+}
+
+/// How long a task's `dry_run_command` can take.
+const DRY_RUN_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Serialize)]
+struct DryRun {
+    command: Vec<String>,
+    exit_code: Option<i32>,
+    /// Both stdout and stderr, redacted like the task's output.
+    output: String,
+}
+
+#[derive(Serialize)]
+struct Preview {
+    command: Vec<String>,
+    /// Environment variables set for the task. Their values are secrets, so they're masked.
+    env: HashMap<String, &'static str>,
+    /// Taru's own, which tasks are run in.
+    working_directory: Option<String>,
+    user: Option<String>,
+    arguments: HashMap<String, task::ArgumentValue>,
+    dry_run: Option<DryRun>,
+}
+
+/// Shows what running a task would do, without running it, or runs its `dry_run_command` instead.
+#[post("/task/{task}/preview")]
+async fn task_preview(req: HttpRequest, body: web::Payload, data: web::Data<Arc<RwLock<AppState>>>, params: web::Path<(String,)>) -> HttpResponse {
+    if !check_run(&req) {
+        return HttpResponse::NotFound().finish()
+    }
+    let run = match prepare_run(&req, &data, &params, body).await {
+        Ok(run) => run,
+        Err(response) => return response
+    };
+    let invocation = run.invocation;
+
+    // Users who can't see sensitive arguments see the commands with them masked
+    let can_view_arguments = can_view_arguments(&req);
+    let mut shown_variables = run.variables.clone();
+    if !can_view_arguments {
+        for arg in run.task.arguments.iter().filter(|arg| arg.sensitive) {
+            if let Some(variable) = shown_variables.get_mut(&arg.name) {
+                variable.values.iter_mut().for_each(|value| *value = task::MASKED.to_owned());
             }
-            if arg.datatype == cfg::ArgumentType::Enum {
-                if let Some(ref enum_source) = arg.enum_source {
-                    let state = data.tasks.get(enum_source).unwrap().clone();
-                    if !state.read().status.is_finished() {
-                        return Err(HttpResponse::BadRequest().body(format!("Data source of argument {} is not ready yet.", arg.name)));
-                    }
-                    if value.len() == 0 {
-                        return Err(HttpResponse::BadRequest().body(format!("Empty value for argument {}", arg.name)));
-                    }
-                    let output: &[u8] = &*state.read().output;
-                    if output.split(|byte| *byte == b'\n').find(|val| *val == value.as_bytes()).is_none() {
-                        return Err(HttpResponse::BadRequest().body(format!("Argument {} has an invalid value.", arg.name)));
-                    }
-                } else {
-                    return Err(HttpResponse::InternalServerError().body(format!("Argument {} is an enum without a data source, please fix its configuration.", arg.name)));
-                }
+        }
+    }
+
+    let dry_run = match run.task.dry_run_command {
+        Some(ref command) => {
+            let cmdline = match cmdline::render(command, &run.variables) {
+                Ok(cmdline) => cmdline,
+                Err(e) => return HttpResponse::InternalServerError().body(format!("Invalid dry run command: {:#}", e))
+            };
+            // Unlike a preview, a dry run runs something
+            let record = Record::request("dry_run", Outcome::Success, &req).task(&params.0).arguments(task::mask_sensitive(&run.task, &run.arguments));
+            let result = task::check(&cmdline, &invocation.env, b"", DRY_RUN_TIMEOUT).await;
+            audit::log(&req, match result {
+                Ok(Some((exit_code, _))) => record.detail(format!("exit code {}", exit_code.map_or("none".to_owned(), |code| code.to_string()))),
+                Ok(None) => record.status(StatusCode::INTERNAL_SERVER_ERROR).detail("timed out"),
+                Err(ref e) => record.status(StatusCode::INTERNAL_SERVER_ERROR).detail(e.to_string()),
+            });
+            match result {
+                Ok(Some((exit_code, output))) => {
+                    let mut redactor = invocation.redactor.clone();
+                    let mut redacted = redactor.feed(&output);
+                    redacted.extend(redactor.finish());
+                    Some(DryRun {
+                        // Rendering the same command with masked values can't fail either
+                        command: cmdline::render(command, &shown_variables).unwrap_or_default(),
+                        exit_code,
+                        output: String::from_utf8_lossy(&redacted).into_owned(),
+                    })
+                },
+                Ok(None) => return HttpResponse::InternalServerError().body(format!("The dry run didn't finish in {} seconds.", DRY_RUN_TIMEOUT.as_secs())),
+                Err(e) => return HttpResponse::InternalServerError().body(format!("Cannot run the dry run command: {}", e))
             }
-            args.insert(arg.name.clone(), value.clone());
-            state.write().arguments.insert(arg.name.clone(), value.clone());
-        } else {
-            return Err(HttpResponse::BadRequest().body(format!("Missing argument {}", arg.name)));
+        },
+        None => None
+    };
+
+    HttpResponse::Ok().json(Preview {
+        command: cmdline::render(&run.task.command, &shown_variables).unwrap_or_default(),
+        env: invocation.env.keys().map(|name| (name.clone(), task::MASKED)).collect(),
+        working_directory: std::env::current_dir().ok().map(|dir| dir.to_string_lossy().into_owned()),
+        user: run.user,
+        arguments: if can_view_arguments { run.arguments } else { task::mask_sensitive(&run.task, &run.arguments) },
+        dry_run,
+    })
+}
+
+/// Puts a run aside until another user approves it, or it expires.
+fn request_approval(req: &HttpRequest, name: &str, run: PreparedRun, record: Record) -> HttpResponse {
+    let PreparedRun { events, task, state, user, arguments, invocation, .. } = run;
+    // Whoever approves the run couldn't be told apart from an anonymous requester
+    if user.is_none() {
+        audit::log(req, record.status(StatusCode::FORBIDDEN));
+        return HttpResponse::Forbidden().body("Runs of this task have to be requested by a logged in user.")
+    }
+    let now = chrono::Utc::now();
+    let approval = task::Approval {
+        id: rand::thread_rng().sample_iter(&rand::distributions::Alphanumeric).take(16).collect(),
+        requested_by: user,
+        requested_at: now,
+        expires: now + chrono::Duration::seconds(task.approval_timeout as i64),
+        arguments,
+    };
+    {
+        let mut state = state.write();
+        if state.pending.is_some() {
+            audit::log(req, record.status(StatusCode::CONFLICT));
+            return HttpResponse::Conflict().body("A run of this task is already waiting for approval.")
         }
+        state.pending = Some(task::PendingRun { approval: approval.clone(), invocation });
     }
+    audit::log(req, record.status(StatusCode::ACCEPTED).detail(format!("waiting for approval {}", approval.id)));
+    send_message(&events, Event::ApprovalRequested(name.to_owned(), approval.clone()));
 
-    let cmdline = task.command.iter().map(|segment|
-        if segment.starts_with("$") {
-            if args.contains_key(&segment[1..]) {
-                args.get(&segment[1..]).unwrap().clone()
-            } else if segment == "$taru_user" {
-                if let Some(Ok(login)) = req.headers().get("x-user").map(|h| h.to_str()) {
-                    login.to_owned()
-                } else {
-                    String::new()
-                }
-            } else {
-                segment.clone()
+    let data: web::Data<Arc<RwLock<AppState>>> = req.app_data::<web::Data<Arc<RwLock<AppState>>>>().unwrap().clone();
+    let (id, name, timeout) = (approval.id.clone(), name.to_owned(), task.approval_timeout);
+    tokio::spawn(async move {
+        time::delay_for(Duration::from_secs(timeout)).await;
+        let expired = {
+            let mut state = state.write();
+            match state.pending {
+                Some(ref pending) if pending.approval.id == id => state.pending.take(),
+                _ => None
             }
-        } else {
-            segment.clone()
+        };
+        if let Some(expired) = expired {
+            audit::log_to(&data, Record::new("approval_expired", Outcome::Success).user(expired.approval.requested_by).task(&name).detail(id.clone()));
+            send_message(&events, Event::ApprovalResolved(name, id, task::Resolution::Expired, None));
         }
-    ).collect();
+    });
 
-    state.write().output = BytesMut::new();
-    let is_buffered = task.buffered;
-    if task::spawn_task(events, state, &cmdline, is_buffered).await.is_err() {
-        return Err(HttpResponse::Conflict().body("The task is already running. Refusing to run two instances in parallel."));
+    HttpResponse::Accepted().json(if can_view_arguments(req) { approval } else { approval.masked(&task) })
+}
+
+#[derive(Deserialize)]
+struct ApprovalQuery {
+    /// The run the user has seen, in case it has been replaced since.
+    id: Option<String>,
+}
+
+#[post("/task/{task}/approve")]
+async fn task_approve(req: HttpRequest, data: web::Data<Arc<RwLock<AppState>>>, params: web::Path<(String,)>, query: web::Query<ApprovalQuery>) -> HttpResponse {
+    if !check_approve(&req) {
+        return HttpResponse::NotFound().finish()
     }
+    let record = Record::request("approve", Outcome::Success, &req).task(&params.0);
+    match approve_run(&req, &data, &params, &query).await {
+        Ok(id) => {
+            audit::log(&req, record.status(StatusCode::OK).detail(id));
+            HttpResponse::Ok().body("Ok")
+        },
+        Err(response) => {
+            audit::log(&req, record.status(response.status()));
+            response
+        }
+    }
+}
 
-    Ok(())
+/// Starts a run waiting for approval, and returns its ID.
+async fn approve_run(req: &HttpRequest, data: &web::Data<Arc<RwLock<AppState>>>, params: &web::Path<(String,)>, query: &ApprovalQuery) -> Result<String, HttpResponse> {
+    let approver = match current_user(req) {
+        Some(approver) => approver,
+        None => return Err(HttpResponse::Forbidden().body("Runs can only be approved by users who have authenticated."))
+    };
+    let (events, state) = {
+        let data = data.read();
+        (data.events.clone(), data.tasks.get(&params.0).unwrap().clone())
+    };
+
+    let pending = {
+        let mut state = state.write();
+        match state.pending {
+            None => return Err(HttpResponse::NotFound().body("There's no run of this task waiting for approval.")),
+            Some(ref pending) if query.id.as_ref().map_or(false, |id| *id != pending.approval.id) => {
+                return Err(HttpResponse::Conflict().body("The run waiting for approval isn't the one you've seen."))
+            },
+            Some(ref pending) if pending.approval.requested_by.as_ref() == Some(&approver) => {
+                return Err(HttpResponse::Forbidden().body("Runs have to be approved by someone other than who requested them."))
+            },
+            _ => {}
+        }
+        if state.status == task::TaskStatus::Running {
+            return Err(HttpResponse::Conflict().body("The task is already running. Refusing to run two instances in parallel."))
+        }
+        let pending = state.pending.take().unwrap();
+        // The timer hasn't gone off yet
+        if pending.approval.expires <= chrono::Utc::now() {
+            send_message(&events, Event::ApprovalResolved(params.0.clone(), pending.approval.id, task::Resolution::Expired, None));
+            return Err(HttpResponse::NotFound().body("There's no run of this task waiting for approval."))
+        }
+        state.arguments = pending.approval.arguments.clone();
+        state.output = BytesMut::new();
+        pending
+    };
+
+    let id = pending.approval.id.clone();
+    if task::spawn_task(events.clone(), state, pending.invocation).await.is_err() {
+        return Err(HttpResponse::Conflict().body("The task is already running. Refusing to run two instances in parallel."))
+    }
+    send_message(&events, Event::ApprovalResolved(params.0.clone(), id.clone(), task::Resolution::Approved, Some(approver)));
+    Ok(id)
+}
+
+/// Turns down a run, which can also be done by the user who has requested it.
+#[post("/task/{task}/reject")]
+async fn task_reject(req: HttpRequest, data: web::Data<Arc<RwLock<AppState>>>, params: web::Path<(String,)>, query: web::Query<ApprovalQuery>) -> HttpResponse {
+    if !can_approve(&req) && !can_run(&req) && !permission_denied(&req, "can_approve or can_run") {
+        return HttpResponse::NotFound().finish()
+    }
+    let record = Record::request("reject", Outcome::Success, &req).task(&params.0);
+    match reject_run(&req, &data, &params, &query) {
+        Ok(id) => {
+            audit::log(&req, record.status(StatusCode::OK).detail(id));
+            HttpResponse::Ok().body("Ok")
+        },
+        Err(response) => {
+            audit::log(&req, record.status(response.status()));
+            response
+        }
+    }
+}
+
+/// Drops a run waiting for approval, and returns its ID.
+fn reject_run(req: &HttpRequest, data: &web::Data<Arc<RwLock<AppState>>>, params: &web::Path<(String,)>, query: &ApprovalQuery) -> Result<String, HttpResponse> {
+    let user = current_user(req);
+    let (events, state) = {
+        let data = data.read();
+        (data.events.clone(), data.tasks.get(&params.0).unwrap().clone())
+    };
+
+    let id = {
+        let mut state = state.write();
+        match state.pending {
+            None => return Err(HttpResponse::NotFound().body("There's no run of this task waiting for approval.")),
+            Some(ref pending) if query.id.as_ref().map_or(false, |id| *id != pending.approval.id) => {
+                return Err(HttpResponse::Conflict().body("The run waiting for approval isn't the one you've seen."))
+            },
+            Some(ref pending) if !can_approve(req) && (user.is_none() || pending.approval.requested_by != user) => {
+                return Err(HttpResponse::Forbidden().body("Only approvers can reject runs requested by someone else."))
+            },
+            _ => {}
+        }
+        state.pending.take().unwrap().approval.id
+    };
+    send_message(&events, Event::ApprovalResolved(params.0.clone(), id.clone(), task::Resolution::Rejected, user));
+    Ok(id)
+}
+
+#[get("/task/{task}/arguments/{argument}/options")]
+async fn task_argument_options(req: HttpRequest, data: web::Data<Arc<RwLock<AppState>>>, params: web::Path<(String, String)>) -> HttpResponse {
+    if !check_run(&req) {
+        return HttpResponse::NotFound().finish()
+    }
+    let mut input = match input::read_query(&req) {
+        Ok(input) => input,
+        Err(response) => return response
+    };
+    let (task, restrictions) = {
+        let data = data.read();
+        (data.config.tasks.get(&params.0).unwrap().clone(), data.config.restrictions(auth::identity(&req).as_ref(), &params.0))
+    };
+    let arg = match task.arguments.iter().find(|arg| arg.name == params.1) {
+        Some(arg) => arg,
+        None => return HttpResponse::NotFound().finish()
+    };
+    if arg.datatype != cfg::ArgumentType::Enum {
+        return HttpResponse::BadRequest().body(format!("Argument {} is not an enum.", arg.name));
+    }
+
+    // The arguments the choices depend on are validated just as they would be when running the task
+    let dependencies = arguments::dependencies(&task, arg);
+    let mut ctx = arguments::Context::new(data.get_ref(), &params.0, current_user(&req));
+    ctx.restrictions = restrictions;
+    for dependency in task.arguments.iter().filter(|dependency| dependencies.contains(&dependency.name)) {
+        let values = match arguments::resolve(dependency, input.take(dependency), &ctx).await {
+            Ok(values) => values,
+            Err(e) => return e.into()
+        };
+        ctx.values.insert(dependency.name.clone(), values);
+    }
+
+    match arguments::choices(arg, &ctx).await {
+        Ok(Some(mut choices)) => {
+            choices.retain(|choice| ctx.restrictions.allows(&arg.name, &choice.value));
+            HttpResponse::Ok().json(choices)
+        },
+        Ok(None) => HttpResponse::BadRequest().body(format!("Data source of argument {} is not ready yet.", arg.name)),
+        Err(e) => e.into()
+    }
 }
 
 async fn stream_task(req: &HttpRequest, data: &web::Data<Arc<RwLock<AppState>>>, params: &web::Path<(String,)>, print_output: bool) -> Result<HttpResponse, HttpResponse> {
-    if !can_view_output(&req) {
+    if !check_view_output(req) {
         return Err(HttpResponse::NotFound().finish())
     }
 
@@ -221,7 +606,7 @@ struct WaitForStatus {
 }
 
 async fn wait_for_status(req: &HttpRequest, data: &web::Data<Arc<RwLock<AppState>>>, params: &web::Path<(String,)>, query: &web::Query<WaitForStatus>) -> Result<HttpResponse, HttpResponse> {
-    if !can_view_output(&req) {
+    if !check_view_output(req) {
         return Err(HttpResponse::NotFound().finish())
     }
 
@@ -232,7 +617,6 @@ async fn wait_for_status(req: &HttpRequest, data: &web::Data<Arc<RwLock<AppState
     };
 
     while let Some(msg) = receiver.next().await {
-        println!("{:?}", msg);
         if let TaskOutput::Finished(code) = msg {
             let code = code.unwrap_or(-1);
             let mut resp = HttpResponse::build(StatusCode::from_u16(520).unwrap());
@@ -249,13 +633,13 @@ async fn wait_for_status(req: &HttpRequest, data: &web::Data<Arc<RwLock<AppState
 }
 
 #[post("/task/{task}/output")]
-async fn task_run_stream(req: HttpRequest, data: web::Data<Arc<RwLock<AppState>>>, params: web::Path<(String,)>) -> HttpResponse {
+async fn task_run_stream(req: HttpRequest, body: web::Payload, data: web::Data<Arc<RwLock<AppState>>>, params: web::Path<(String,)>) -> HttpResponse {
     let stream = match stream_task(&req, &data, &params, false).await {
         Ok(stream) => stream,
         Err(response) => return response
     };
 
-    if let Err(response) = run_task(&req, &data, &params).await {
+    if let Err(response) = run_task(&req, &data, &params, body).await {
         return response
     }
 
@@ -263,8 +647,8 @@ async fn task_run_stream(req: HttpRequest, data: web::Data<Arc<RwLock<AppState>>
 }
 
 #[post("/task/{task}")]
-async fn task_run(req: HttpRequest, data: web::Data<Arc<RwLock<AppState>>>, params: web::Path<(String,)>) -> actix_web::Result<HttpResponse> {
-    if let Err(response) = run_task(&req, &data, &params).await {
+async fn task_run(req: HttpRequest, body: web::Payload, data: web::Data<Arc<RwLock<AppState>>>, params: web::Path<(String,)>) -> actix_web::Result<HttpResponse> {
+    if let Err(response) = run_task(&req, &data, &params, body).await {
         return Ok(response)
     }
 
@@ -273,7 +657,7 @@ async fn task_run(req: HttpRequest, data: web::Data<Arc<RwLock<AppState>>>, para
 
 #[get("/task/{task}/status")]
 async fn task_wait(req: HttpRequest, data: web::Data<Arc<RwLock<AppState>>>, params: web::Path<(String,)>, query: web::Query<WaitForStatus>) -> Result<HttpResponse, HttpResponse> {
-    if !can_view_status(&req) {
+    if !check_view_status(&req) {
         return Ok(HttpResponse::NotFound().finish())
     }
 
@@ -295,13 +679,13 @@ async fn task_wait(req: HttpRequest, data: web::Data<Arc<RwLock<AppState>>>, par
 }
 
 #[post("/task/{task}/status")]
-async fn task_run_wait(req: HttpRequest, data: web::Data<Arc<RwLock<AppState>>>, params: web::Path<(String,)>, query: web::Query<WaitForStatus>) -> Result<HttpResponse, HttpResponse> {
-    if !can_view_status(&req) {
+async fn task_run_wait(req: HttpRequest, body: web::Payload, data: web::Data<Arc<RwLock<AppState>>>, params: web::Path<(String,)>, query: web::Query<WaitForStatus>) -> Result<HttpResponse, HttpResponse> {
+    if !check_view_status(&req) {
         return Ok(HttpResponse::NotFound().finish())
     }
 
 
-    if let Err(response) = run_task(&req, &data, &params).await {
+    if let Err(response) = run_task(&req, &data, &params, body).await {
         return Ok(response)
     }
 
@@ -310,23 +694,26 @@ async fn task_run_wait(req: HttpRequest, data: web::Data<Arc<RwLock<AppState>>>,
 
 #[post("/task/{task}/stop")]
 async fn task_stop(req: HttpRequest, data: web::Data<Arc<RwLock<AppState>>>, params: web::Path<(String,)>) -> HttpResponse {
-    if !can_run(&req) {
+    if !check_run(&req) {
         return HttpResponse::NotFound().finish()
     }
 
+    let record = Record::request("stop", Outcome::Success, &req).task(&params.0);
     let state = data.read().tasks.get(&params.0).unwrap().clone();
     if state.read().status == task::TaskStatus::Running {
         if let Err(e) = task::stop_task(state).await {
+            audit::log(&req, record.status(StatusCode::INTERNAL_SERVER_ERROR).detail(e.to_string()));
             return HttpResponse::InternalServerError().body(format!("Stopping task failed: {}", e));
         }
     }
 
+    audit::log(&req, record.status(StatusCode::OK));
     HttpResponse::Ok().body("Ok")
 }
 
 #[get("/task/{task}/output")]
 async fn task_stream(req: HttpRequest, data: web::Data<Arc<RwLock<AppState>>>, params: web::Path<(String,)>) -> HttpResponse {
-    if !can_view_output(&req) {
+    if !check_view_output(&req) {
         return HttpResponse::NotFound().finish()
     }
 
@@ -347,7 +734,7 @@ async fn task_stream(req: HttpRequest, data: web::Data<Arc<RwLock<AppState>>>, p
 
 #[post("/task/{task}/data/{name}")]
 async fn task_change_data(req: HttpRequest, mut body: web::Payload, data: web::Data<Arc<RwLock<AppState>>>, params: web::Path<(String, String)>) -> actix_web::Result<HttpResponse> {
-    if !can_change_data(&req) {
+    if !check_change_data(&req) {
         return Ok(HttpResponse::NotFound().finish())
     }
 
@@ -356,11 +743,15 @@ async fn task_change_data(req: HttpRequest, mut body: web::Payload, data: web::D
         bytes.extend_from_slice(&item?);
     }
 
-    let data_read = data.read();
-    let mut task = data_read.tasks.get(&params.0).unwrap().write();
-    let value = String::from_utf8_lossy(&bytes).into_owned();
-    task.data.insert(params.1.clone(), value.clone());
-    send_message(&data_read.events, Event::TaskData(task.name.clone(), params.1.clone(), value));
+    {
+        let data_read = data.read();
+        let mut task = data_read.tasks.get(&params.0).unwrap().write();
+        let value = String::from_utf8_lossy(&bytes).into_owned();
+        task.data.insert(params.1.clone(), value.clone());
+        send_message(&data_read.events, Event::TaskData(task.name.clone(), params.1.clone(), value));
+    }
+    // Values can be large, so only their names are recorded
+    audit::log(&req, Record::request("change_data", Outcome::Success, &req).status(StatusCode::OK).task(&params.0).detail(params.1.clone()));
 
     Ok(HttpResponse::Ok().body("Ok"))
 }
@@ -369,18 +760,39 @@ async fn task_change_data(req: HttpRequest, mut body: web::Payload, data: web::D
 async fn sse(req: HttpRequest, data: web::Data<Arc<RwLock<AppState>>>) -> HttpResponse {
     let receiver = data.read().events.subscribe();
     let task_access: HashSet<_> = get_view_status_tasks(&req);
+    let arguments_access: HashSet<_> = get_view_arguments_tasks(&req);
+    let state = data.get_ref().clone();
 
     let first_ping = stream::once(future::ready(Ok::<_, Infallible>(Event::Ping.to_event())));
-    let stream = receiver.into_stream().scan(task_access, |task_access, result|
+    let stream = receiver.into_stream().scan(task_access, move |task_access, result|
         match result {
             Ok(event) => {
                 match &event {
                     Event::Started(name, _)
-                    | Event::Finished(name, _) => if !task_access.contains(name) {
+                    | Event::Finished(name, _)
+                    | Event::ApprovalRequested(name, _)
+                    | Event::ApprovalResolved(name, _, _, _) => if !task_access.contains(name) {
                         return future::ready(None)
                     },
                     _ => {}
                 }
+                let event = match event {
+                    Event::Started(name, arguments) if !arguments_access.contains(&name) => {
+                        let masked = match state.read().config.tasks.get(&name) {
+                            Some(task) => task::mask_sensitive(task, &arguments),
+                            None => HashMap::new()
+                        };
+                        Event::Started(name, masked)
+                    },
+                    Event::ApprovalRequested(name, approval) if !arguments_access.contains(&name) => {
+                        let masked = match state.read().config.tasks.get(&name) {
+                            Some(task) => approval.masked(task),
+                            None => task::Approval { arguments: HashMap::new(), ..approval }
+                        };
+                        Event::ApprovalRequested(name, masked)
+                    },
+                    event => event
+                };
                 future::ready(Some(Ok::<_, Infallible>(event.to_event())))
             }
             Err(_) => future::ready(None)
@@ -399,6 +811,24 @@ fn forbidden(req: ServiceRequest) -> Pin<Box<dyn Future<Output = Result<ServiceR
     ).boxed_local()
 }
 
+/// Sends browsers to log in, if users can, and tells API clients to authenticate.
+fn unauthenticated(req: ServiceRequest) -> Pin<Box<dyn Future<Output = Result<ServiceResponse, ActixError>>>> {
+    let has_login = {
+        let state = req.app_data::<Arc<RwLock<AppState>>>().unwrap();
+        let config = &state.read().config;
+        config.auth.as_ref().and_then(|auth| auth.provider()).is_some() || config.has_passwords()
+    };
+    let response = if has_login && !req.path().starts_with("/api/") {
+        let location = url::form_urlencoded::Serializer::new(String::new()).append_pair("return", req.path()).finish();
+        HttpResponse::Found().header("location", format!("/auth/login?{}", location)).finish()
+    } else if has_login {
+        HttpResponse::Unauthorized().body("Please log in.")
+    } else {
+        HttpResponse::Forbidden().finish()
+    };
+    future::ready(Ok(req.into_response(response))).boxed_local()
+}
+
 #[actix_rt::main]
 async fn main() -> std::io::Result<()> {
     // systemctl won't know how to connect to the user instance unless this env var is set
@@ -406,34 +836,76 @@ async fn main() -> std::io::Result<()> {
     let mut listenfd = ListenFd::from_env();
     let data = AppState::new(std::env::args().collect::<Vec<_>>().get(1).expect("The first argument must be a path to the config file."));
     let signal_data = data.clone();
+    // Reloading the configuration can't change these, or it would invalidate all sessions
+    let session_key = auth::session_key(&data.read().config);
+    let tls = data.read().config.tls.clone();
+    let secure_cookies = tls.is_some() || data.read().config.auth.as_ref().and_then(|auth| auth.url.as_ref()).map_or(false, |url| url.starts_with("https://"));
+    let session_lifetime = data.read().config.session_lifetime() as i64;
 
-    let mut server = HttpServer::new(move ||
+    let app = move ||
         App::new().data(data.clone())
             .wrap_fn(|req, srv| {
-                if req.app_data::<Arc<RwLock<AppState>>>().unwrap().read().config.users.len() == 0 {
-                    // Disable authorization if there are no users defined
+                if req.path().starts_with("/auth/") {
                     return srv.call(req)
                 }
-
-                // Otherwise, reject any unauthorized users.
-                if let Some(Ok(login)) = req.headers().get("x-user").map(|h| h.to_str()) {
-                    if !req.app_data::<Arc<RwLock<AppState>>>().unwrap().read().config.users.contains_key(login) {
-                        // We don't know this user
-                        return forbidden(req)
+                let result = auth::authenticate(&req, &req.app_data::<Arc<RwLock<AppState>>>().unwrap().read());
+                let reason = match result {
+                    Err(auth::Denied::Forbidden) => Some("unknown user"),
+                    Err(auth::Denied::InvalidToken) => Some("invalid token"),
+                    Err(auth::Denied::UntrustedProxy) => Some("untrusted proxy"),
+                    Err(auth::Denied::Csrf) => Some("missing CSRF token"),
+                    _ => None
+                };
+                if let Some(reason) = reason {
+                    let record = Record::service_request("authentication_denied", Outcome::Denied, &req).detail(reason);
+                    audit::log_to(&req.app_data::<Arc<RwLock<AppState>>>().unwrap(), record);
+                }
+                match result {
+                    Ok(Some(identity)) => {
+                        req.extensions_mut().insert(identity);
+                        srv.call(req)
+                    },
+                    // Authorization is disabled if there are no users defined
+                    Ok(None) => srv.call(req),
+                    Err(auth::Denied::Unauthenticated) => unauthenticated(req),
+                    // We don't know this user
+                    Err(auth::Denied::Forbidden) => forbidden(req),
+                    Err(auth::Denied::InvalidToken) => {
+                        let response = HttpResponse::Unauthorized().body("The token is invalid, or has expired.");
+                        future::ready(Ok(req.into_response(response))).boxed_local()
+                    },
+                    Err(auth::Denied::UntrustedProxy) => {
+                        let response = HttpResponse::Forbidden().body("The user header is only accepted from a trusted proxy.");
+                        future::ready(Ok(req.into_response(response))).boxed_local()
+                    },
+                    Err(auth::Denied::Csrf) => {
+                        let response = HttpResponse::Forbidden().body("The X-CSRF-Token header is missing, or doesn't match the session.");
+                        future::ready(Ok(req.into_response(response))).boxed_local()
                     }
-                    srv.call(req)
-                } else {
-                    // No X-User header has been set, or it was somehow incorrect.
-                    forbidden(req)
                 }
             })
+            .wrap(
+                CookieSession::private(&session_key)
+                    .name("taru-session")
+                    .path("/")
+                    .http_only(true)
+                    .same_site(SameSite::Lax)
+                    .secure(secure_cookies)
+                    .max_age(session_lifetime)
+            )
+            .service(
+                Scope::new("/auth").service(auth::auth_login).service(auth::auth_password_login).service(auth::auth_callback)
+                    .service(auth::auth_session).service(auth::auth_logout)
+            )
             .service(
                 Scope::new("/api/v1").service(sse).service(tasks)
                     .service(task_run).service(task_stream).service(task_run_stream).service(task_stop)
                     .service(task_change_data).service(task_run_wait).service(task_wait)
+                    .service(task_argument_options).service(task_approve).service(task_reject)
+                    .service(task_preview)
+                    .service(tokens::token_list).service(tokens::token_create).service(tokens::token_revoke)
             )
-            .service(Files::new("/", "public").index_file("index.html"))
-    );
+            .service(Files::new("/", "public").index_file("index.html"));
 
     tokio::spawn(async move {
         let mut sighup = signal(SignalKind::hangup()).unwrap();
@@ -454,6 +926,25 @@ async fn main() -> std::io::Result<()> {
         }
     });
 
+    if let Some(tls) = tls {
+        // HttpServer can't pass client certificates on to the application, so HTTPS is served without it
+        let tls_config = tls::server_config(&tls).unwrap_or_else(|e| panic!("{:#}", e));
+        let factory = move || {
+            HttpService::build()
+                .on_connect(tls::client_certificate)
+                .finish(apply_fn_factory(map_config(app(), |_| AppConfig::default()), |req, app| app.call(tls::mark_secure(req))))
+                .rustls(tls_config.clone())
+        };
+        let server = Server::build();
+        let server = if let Some(l) = listenfd.take_tcp_listener(0).unwrap() {
+            server.listen("taru", l, factory)?
+        } else {
+            server.bind("taru", "0.0.0.0:3000", factory)?
+        };
+        return server.run().await
+    }
+
+    let mut server = HttpServer::new(app);
     server = if let Some(l) = listenfd.take_tcp_listener(0).unwrap() {
         server.listen(l)?
     } else {
@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+use argon2::{Argon2, PasswordHash, PasswordVerifier};
+use anyhow::{Context, bail};
+
+use crate::cfg::Config;
+
+/// Whether a hash is in one of the formats passwords can be checked against.
+pub fn is_supported(hash: &str) -> bool {
+    if hash.starts_with("$argon2") {
+        return PasswordHash::new(hash).is_ok()
+    }
+    ["$2a$", "$2b$", "$2y$"].iter().any(|prefix| hash.starts_with(prefix))
+}
+
+fn verify(password: &str, hash: &str) -> bool {
+    if hash.starts_with("$argon2") {
+        match PasswordHash::new(hash) {
+            Ok(hash) => Argon2::default().verify_password(password.as_bytes(), &hash).is_ok(),
+            Err(_) => false
+        }
+    } else {
+        bcrypt::verify(password, hash).unwrap_or(false)
+    }
+}
+
+/// Reads a file of `login:hash` lines. Empty lines, and those starting with `#`, are skipped.
+fn read_file(path: &str) -> anyhow::Result<HashMap<String, String>> {
+    let contents = std::fs::read_to_string(path).with_context(|| format!("Cannot read {}", path))?;
+    let mut hashes = HashMap::new();
+    for (number, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue
+        }
+        match line.split_once(':') {
+            Some((login, hash)) if is_supported(hash) => { hashes.insert(login.to_owned(), hash.to_owned()); },
+            _ => bail!("Line {} of {} isn't a login followed by an argon2 or bcrypt hash", number + 1, path)
+        }
+    }
+    Ok(hashes)
+}
+
+/// Checks a user's password.
+///
+/// The password file is read every time, so that it can be changed without reloading Taru.
+/// This is slow on purpose, so it shouldn't be called on the server's threads.
+pub fn check(config: &Config, login: &str, password: &str) -> anyhow::Result<bool> {
+    let mut hash = config.users.get(login).and_then(|user| user.password.clone());
+    if hash.is_none() {
+        if let Some(ref path) = config.auth.as_ref().and_then(|auth| auth.password_file.clone()) {
+            hash = read_file(path)?.remove(login);
+        }
+    }
+    Ok(hash.map_or(false, |hash| verify(password, &hash)))
+}
@@ -0,0 +1,184 @@
+use regex::bytes::Regex;
+
+const MASK: &[u8] = b"********";
+/// How much of an unfinished line is held back waiting for its end before the rules are applied anyway.
+/// Half of it is still held back then, so that matches it ends with aren't cut in two.
+const MAX_LINE: usize = 65536;
+
+#[derive(Clone)]
+pub struct Rule {
+    pattern: Regex,
+    replacement: Vec<u8>,
+}
+
+impl Rule {
+    pub fn new(pattern: Regex, replacement: &str) -> Self {
+        Rule { pattern, replacement: replacement.as_bytes().to_owned() }
+    }
+}
+
+/// Masks occurrences of secret values, and matches of redaction rules, in a stream of output chunks.
+///
+/// A secret can be split between two reads, so the tail of a chunk that could be the beginning
+/// of a secret is held back until the next chunk (or the end of the stream) decides it.
+/// Rules are applied to whole lines instead, so a match can't span more than one line.
+#[derive(Clone, Default)]
+pub struct Redactor {
+    secrets: Vec<Vec<u8>>,
+    rules: Vec<Rule>,
+    pending: Vec<u8>,
+    line: Vec<u8>,
+}
+
+impl Redactor {
+    pub fn new<I, S>(secrets: I, rules: Vec<Rule>) -> Self where I: IntoIterator<Item = S>, S: Into<Vec<u8>> {
+        let mut secrets: Vec<Vec<u8>> = secrets.into_iter().map(Into::into).filter(|s| !s.is_empty()).collect();
+        // Prefer the longest match if one secret is a prefix of another
+        secrets.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        secrets.dedup();
+        Redactor { secrets, rules, pending: vec![], line: vec![] }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.secrets.is_empty() && self.rules.is_empty()
+    }
+
+    /// Returns the part of the output that is safe to pass on.
+    pub fn feed(&mut self, chunk: &[u8]) -> Vec<u8> {
+        if self.is_empty() {
+            return chunk.to_owned()
+        }
+        self.pending.extend_from_slice(chunk);
+        let output = self.mask_secrets(false);
+        self.apply_rules(output, false)
+    }
+
+    /// Returns whatever has been held back. Call this when the stream ends.
+    pub fn finish(&mut self) -> Vec<u8> {
+        let output = self.mask_secrets(true);
+        self.apply_rules(output, true)
+    }
+
+    fn mask_secrets(&mut self, finished: bool) -> Vec<u8> {
+        if self.secrets.is_empty() {
+            return self.pending.split_off(0)
+        }
+        let mut output = Vec::with_capacity(self.pending.len());
+        let mut i = 0;
+        'scan: while i < self.pending.len() {
+            let rest = &self.pending[i..];
+            if !finished && self.secrets.iter().any(|s| s.len() > rest.len() && s.starts_with(rest)) {
+                break
+            }
+            for secret in &self.secrets {
+                if rest.starts_with(secret) {
+                    output.extend_from_slice(MASK);
+                    i += secret.len();
+                    continue 'scan;
+                }
+            }
+            output.push(rest[0]);
+            i += 1;
+        }
+        self.pending.drain(..i);
+        output
+    }
+
+    fn apply_rules(&mut self, output: Vec<u8>, finished: bool) -> Vec<u8> {
+        if self.rules.is_empty() {
+            return output
+        }
+        self.line.extend_from_slice(&output);
+        // Carriage returns count as line ends too, otherwise progress bars would be held back until they finish
+        let mut end = if finished {
+            self.line.len()
+        } else {
+            self.line.iter().rposition(|byte| *byte == b'\n' || *byte == b'\r').map(|pos| pos + 1).unwrap_or(0)
+        };
+        if self.line.len() - end > MAX_LINE {
+            end = self.forced_end(end);
+        }
+        let mut ready: Vec<u8> = self.line.drain(..end).collect();
+        for rule in &self.rules {
+            ready = rule.pattern.replace_all(&ready, &rule.replacement[..]).into_owned();
+        }
+        ready
+    }
+
+    /// Where to cut a line that's too long, after `start` and outside of any match.
+    fn forced_end(&self, start: usize) -> usize {
+        let mut end = self.line.len() - MAX_LINE / 2;
+        let mut moved = true;
+        while moved {
+            moved = false;
+            for rule in &self.rules {
+                if let Some(m) = rule.pattern.find_iter(&self.line[start..]).find(|m| start + m.start() < end && start + m.end() > end) {
+                    end = start + m.start();
+                    moved = true;
+                }
+            }
+        }
+        // A match this long can't be held back any more, it's only masked as far as it goes
+        if end == 0 {
+            return self.line.len()
+        }
+        end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn redact(redactor: &mut Redactor, chunks: &[&[u8]]) -> Vec<u8> {
+        let mut output: Vec<u8> = chunks.iter().flat_map(|chunk| redactor.feed(chunk)).collect();
+        output.extend(redactor.finish());
+        output
+    }
+
+    fn rule(pattern: &str) -> Rule {
+        Rule::new(Regex::new(pattern).unwrap(), "[REDACTED]")
+    }
+
+    #[test]
+    fn secret_split_between_chunks() {
+        let mut redactor = Redactor::new(vec!["hunter2"], vec![]);
+        assert_eq!(redact(&mut redactor, &[b"password: hun", b"ter2\n"]), b"password: ********\n");
+    }
+
+    #[test]
+    fn match_split_between_chunks() {
+        let mut redactor = Redactor::new(Vec::<Vec<u8>>::new(), vec![rule(r"token=\w+")]);
+        assert_eq!(redact(&mut redactor, &[b"token=ab", b"cd ok\nnext"]), b"[REDACTED] ok\nnext");
+    }
+
+    #[test]
+    fn match_split_by_a_long_line() {
+        let mut redactor = Redactor::new(Vec::<Vec<u8>>::new(), vec![rule(r"secret-[0-9]{4}")]);
+        let mut long = vec![b'-'; MAX_LINE];
+        long.extend_from_slice(b"secret-12");
+        // Most of the line is passed on, but not the beginning of the match
+        let first = redactor.feed(&long);
+        assert!(first.len() >= MAX_LINE / 2);
+        assert!(first.iter().all(|byte| *byte == b'-'));
+
+        let mut output = first;
+        output.extend(redactor.feed(b"34 done\n"));
+        output.extend(redactor.finish());
+        let mut expected = vec![b'-'; MAX_LINE];
+        expected.extend_from_slice(b"[REDACTED] done\n");
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn long_line_is_not_cut_inside_a_match() {
+        let mut redactor = Redactor::new(Vec::<Vec<u8>>::new(), vec![rule(r"token=\w+")]);
+        let mut long = vec![b'-'; MAX_LINE / 2];
+        long.extend_from_slice(b"token=abcdefghij");
+        long.extend(vec![b'-'; MAX_LINE / 2 - 8]);
+        let mut expected = vec![b'-'; MAX_LINE / 2];
+        expected.extend_from_slice(b"[REDACTED]");
+        expected.extend(vec![b'-'; MAX_LINE / 2 - 8]);
+        assert_eq!(redact(&mut redactor, &[&long]), expected);
+    }
+}
@@ -0,0 +1,31 @@
+use std::path::PathBuf;
+use std::io::{Error, ErrorKind, Result};
+
+use crate::cfg::SecretSource;
+
+pub fn read_secret(source: &SecretSource) -> Result<String> {
+    let path = match source {
+        SecretSource::File(path) => PathBuf::from(path),
+        SecretSource::Credential(name) => {
+            // This is the same directory `systemd-creds cat` reads from. systemd sets it for
+            // services using LoadCredential= or LoadCredentialEncrypted=.
+            let dir = std::env::var_os("CREDENTIALS_DIRECTORY").ok_or_else(||
+                Error::new(ErrorKind::NotFound, "CREDENTIALS_DIRECTORY is not set")
+            )?;
+            if name.contains('/') {
+                return Err(Error::new(ErrorKind::InvalidInput, "credential names cannot contain slashes"));
+            }
+            PathBuf::from(dir).join(name)
+        }
+    };
+
+    let mut value = std::fs::read_to_string(path)?;
+    // Files usually end with a newline that isn't a part of the secret
+    if value.ends_with('\n') {
+        value.pop();
+        if value.ends_with('\r') {
+            value.pop();
+        }
+    }
+    Ok(value)
+}
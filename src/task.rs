@@ -10,10 +10,19 @@ use parking_lot::RwLock;
 use std::sync::Arc;
 use serde::Serialize;
 use bytes::{BytesMut, BufMut};
+use chrono::{DateTime, Utc};
 use tokio::process::Command as AsyncCommand;
+use tokio::io::AsyncWriteExt;
+use tokio::time::{self, Duration};
 
 use crate::event::{Event, send_message};
 use crate::broadcast::BroadcastChannel;
+use crate::redact::{Redactor, Rule};
+use crate::cfg::{TaskConfig, SecretSource};
+use crate::cmdline::{self, Variable};
+use crate::secrets::read_secret;
+use crate::input::Upload;
+use anyhow::{Context, anyhow};
 use libc::{fcntl, F_GETFL, F_SETFL, O_NONBLOCK};
 
 const TOKEN_STDOUT: Token = Token(0);
@@ -22,6 +31,42 @@ const BUF_SIZE: usize = 10240;
 
 pub struct TaskAlreadyRunning;
 
+/// Everything needed to start a task's process.
+pub struct Invocation {
+    pub cmdline: Vec<String>,
+    pub env: HashMap<String, String>,
+    pub buffered: bool,
+    /// Applied to the output before it's sent to subscribers or buffered.
+    pub redactor: Redactor,
+    /// Files uploaded for the task, removed once it finishes.
+    pub uploads: Vec<Upload>,
+}
+
+impl Invocation {
+    /// Substitutes the variables in the task's command, and reads its secrets.
+    pub fn prepare(task: &TaskConfig, secrets: &HashMap<String, SecretSource>, variables: &HashMap<String, Variable>) -> anyhow::Result<Self> {
+        let cmdline = cmdline::render(&task.command, variables).context("Invalid command")?;
+
+        let mut env = HashMap::new();
+        for (variable, secret) in &task.secrets {
+            let source = secrets.get(secret).ok_or_else(|| anyhow!("Secret {} is not defined, please fix the task's configuration.", secret))?;
+            let value = read_secret(source).with_context(|| format!("Reading secret {} failed", secret))?;
+            env.insert(variable.clone(), value);
+        }
+
+        let rules = task.redact.iter().map(|rule| Rule::new(rule.pattern.bytes().clone(), &rule.replacement)).collect();
+        Ok(Invocation {
+            cmdline,
+            redactor: Redactor::new(env.values().cloned(), rules),
+            env,
+            buffered: task.buffered,
+            uploads: vec![],
+        })
+    }
+}
+
+pub const MASKED: &str = "********";
+
 #[derive(Debug, Serialize, Clone)]
 pub enum TaskOutput {
     Stdout(Vec<u8>),
@@ -29,6 +74,62 @@ pub enum TaskOutput {
     Finished(Option<i32>)
 }
 
+/// The value of an argument a task has been run with.
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum ArgumentValue {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+impl ArgumentValue {
+    fn masked(&self) -> Self {
+        match self {
+            ArgumentValue::Single(_) => ArgumentValue::Single(MASKED.to_owned()),
+            ArgumentValue::Multiple(values) => ArgumentValue::Multiple(vec![MASKED.to_owned(); values.len()]),
+        }
+    }
+}
+
+/// Replaces the values of sensitive arguments, for those who aren't allowed to see them.
+pub fn mask_sensitive(task: &TaskConfig, values: &HashMap<String, ArgumentValue>) -> HashMap<String, ArgumentValue> {
+    values.iter().map(|(name, value)| {
+        let sensitive = task.arguments.iter().any(|arg| &arg.name == name && arg.sensitive);
+        (name.clone(), if sensitive { value.masked() } else { value.clone() })
+    }).collect()
+}
+
+/// A run waiting for another user to approve it.
+#[derive(Debug, Serialize, Clone)]
+pub struct Approval {
+    pub id: String,
+    pub requested_by: Option<String>,
+    pub requested_at: DateTime<Utc>,
+    pub expires: DateTime<Utc>,
+    pub arguments: HashMap<String, ArgumentValue>,
+}
+
+impl Approval {
+    pub fn masked(&self, task: &TaskConfig) -> Approval {
+        Approval { arguments: mask_sensitive(task, &self.arguments), ..self.clone() }
+    }
+}
+
+/// What has become of a run that was waiting for approval.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all="lowercase")]
+pub enum Resolution {
+    Approved,
+    Rejected,
+    Expired,
+}
+
+/// A run that has been validated, and is ready to start once it's approved.
+pub struct PendingRun {
+    pub approval: Approval,
+    pub invocation: Invocation,
+}
+
 #[derive(Debug, Serialize, Clone, PartialEq, Eq)]
 pub enum TaskStatus {
     New,
@@ -60,7 +161,8 @@ pub struct TaskState {
     pub output: BytesMut,
     pub events: BroadcastChannel<TaskOutput>,
     pub data: HashMap<String, String>,
-    pub arguments: HashMap<String, String>,
+    pub arguments: HashMap<String, ArgumentValue>,
+    pub pending: Option<PendingRun>,
 }
 
 impl TaskState {
@@ -74,6 +176,7 @@ impl TaskState {
             events: BroadcastChannel::new(16),
             data: HashMap::new(),
             arguments: HashMap::new(),
+            pending: None,
         }
     }
 }
@@ -90,7 +193,7 @@ fn set_nonblocking(fd: RawFd) -> std::io::Result<()> {
     Ok(())
 }
 
-pub async fn spawn_task(global_events: Sender<Event>, task: Arc<RwLock<TaskState>>, cmdline: &Vec<String>, buffer: bool) -> Result<(), TaskAlreadyRunning> {
+pub async fn spawn_task(global_events: Sender<Event>, task: Arc<RwLock<TaskState>>, invocation: Invocation) -> Result<(), TaskAlreadyRunning> {
     // This is a mio-based implementation of running a process asynchronously and capturing its
     // stdout and stderr. Mio is used here directly because in order to preserve the order of
     // wakeup events, we need to use one Poll for both streams.
@@ -102,7 +205,9 @@ pub async fn spawn_task(global_events: Sender<Event>, task: Arc<RwLock<TaskState
         return Err(TaskAlreadyRunning)
     }
     cmd.args(&["--user", "--quiet", "--scope", "--collect", &format!("--unit=taru-task-{}", task_locked.name)]);
-    cmd.args(cmdline);
+    cmd.args(&invocation.cmdline);
+    // systemd-run --scope executes the command itself, so it inherits the environment
+    cmd.envs(&invocation.env);
     cmd.stdin(Stdio::null());
     cmd.stdout(writer_out);
     cmd.stderr(writer_err);
@@ -117,6 +222,10 @@ pub async fn spawn_task(global_events: Sender<Event>, task: Arc<RwLock<TaskState
     drop(cmd);  // crucial to drop writing pipes
     let task_name = task_locked.name.clone();
     let task_events = task_locked.events.clone();
+    let buffer = invocation.buffered;
+    let mut redactor_out = invocation.redactor.clone();
+    let mut redactor_err = invocation.redactor;
+    let uploads = invocation.uploads;
     task_locked.status = TaskStatus::Running;
     send_message(&global_events, Event::Started(task_name.clone(), task_locked.arguments.clone()));
     drop(task_locked);
@@ -143,11 +252,15 @@ pub async fn spawn_task(global_events: Sender<Event>, task: Arc<RwLock<TaskState
                                     Err(e) if e.kind() == ErrorKind::WouldBlock => break,
                                     Err(e) => panic!(e)
                                 };
-                                task_events.send(TaskOutput::Stdout(buf[0..res].to_owned())).await;
-
+                                let output = redactor_out.feed(&buf[0..res]);
+                                if output.is_empty() {
+                                    continue
+                                }
                                 if buffer {
-                                    task.write().output.put(&buf[0..res]);
+                                    task.write().output.put(&output[..]);
                                 }
+
+                                task_events.send(TaskOutput::Stdout(output)).await;
                             }
                         } else {
                             loop {
@@ -157,17 +270,37 @@ pub async fn spawn_task(global_events: Sender<Event>, task: Arc<RwLock<TaskState
                                     Err(e) if e.kind() == ErrorKind::WouldBlock => break,
                                     Err(e) => panic!(e)
                                 };
-                                task_events.send(TaskOutput::Stderr(buf[0..res].to_owned())).await;
-
+                                let output = redactor_err.feed(&buf[0..res]);
+                                if output.is_empty() {
+                                    continue
+                                }
                                 if buffer {
-                                    task.write().output.put(&buf[0..res]);
+                                    task.write().output.put(&output[..]);
                                 }
+
+                                task_events.send(TaskOutput::Stderr(output)).await;
                             }
                         };
                     }
                 }
             }
+            // Flush whatever the redactors were holding back waiting for more data
+            let rest = redactor_out.finish();
+            if !rest.is_empty() {
+                if buffer {
+                    task.write().output.put(&rest[..]);
+                }
+                task_events.send(TaskOutput::Stdout(rest)).await;
+            }
+            let rest = redactor_err.finish();
+            if !rest.is_empty() {
+                if buffer {
+                    task.write().output.put(&rest[..]);
+                }
+                task_events.send(TaskOutput::Stderr(rest)).await;
+            }
             let code = child.wait().expect("wait() failed").code();
+            drop(uploads);
             task.write().status = TaskStatus::Finished(code);
             task_events.send(TaskOutput::Finished(code)).await;
             send_message(&global_events, Event::Finished(task_name.clone(), code));
@@ -177,6 +310,60 @@ pub async fn spawn_task(global_events: Sender<Event>, task: Arc<RwLock<TaskState
     Ok(())
 }
 
+/// Runs a command outside of any task's state, and returns its exit code and (redacted) stdout.
+///
+/// Returns `None` if the command doesn't finish in time, in which case it's killed.
+pub async fn capture(invocation: Invocation, timeout: Duration) -> tokio::io::Result<Option<(Option<i32>, Vec<u8>)>> {
+    let child = AsyncCommand::new("systemd-run")
+        .args(["--user", "--quiet", "--scope", "--collect"])
+        .args(&invocation.cmdline)
+        .envs(&invocation.env)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .kill_on_drop(true)
+        .spawn()?;
+    let output = match time::timeout(timeout, child.wait_with_output()).await {
+        Ok(output) => output?,
+        Err(_) => return Ok(None)
+    };
+    let mut redactor = invocation.redactor;
+    let mut stdout = redactor.feed(&output.stdout);
+    stdout.extend(redactor.finish());
+    Ok(Some((output.status.code(), stdout)))
+}
+
+/// Runs a command, passing it `input` on stdin, and returns its exit code and output (both stdout and stderr).
+///
+/// Returns `None` if the command doesn't finish in time, in which case it's killed.
+pub async fn check(cmdline: &[String], env: &HashMap<String, String>, input: &[u8], timeout: Duration) -> tokio::io::Result<Option<(Option<i32>, Vec<u8>)>> {
+    let mut child = AsyncCommand::new("systemd-run")
+        .args(["--user", "--quiet", "--scope", "--collect"])
+        .args(cmdline)
+        .envs(env)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()?;
+    let mut stdin = child.stdin.take().unwrap();
+    let run = async move {
+        // The command doesn't have to read its input, so a broken pipe is fine
+        let _ = stdin.write_all(input).await;
+        drop(stdin);
+        child.wait_with_output().await
+    };
+    match time::timeout(timeout, run).await {
+        Ok(output) => {
+            let output = output?;
+            let mut combined = output.stdout;
+            combined.extend(output.stderr);
+            Ok(Some((output.status.code(), combined)))
+        },
+        Err(_) => Ok(None)
+    }
+}
+
 // This can be async, because it deosn't stream the output
 pub async fn stop_task(state: Arc<RwLock<TaskState>>) -> tokio::io::Result<ExitStatus> {
     let ref name = state.read().name;
@@ -0,0 +1,124 @@
+use std::fs::File;
+use std::io::BufReader;
+use actix_rt::net::TcpStream;
+use actix_tls::rustls::TlsStream;
+use actix_http::Request;
+use http::{header, Uri, uri::Scheme};
+use rustls::{ServerConfig, Session, RootCertStore, NoClientAuth, AllowAnyAuthenticatedClient, AllowAnyAnonymousOrAuthenticatedClient};
+use rustls::internal::pemfile;
+use x509_parser::extensions::GeneralName;
+use anyhow::{Context, anyhow, bail};
+
+use crate::cfg::{TlsConfig, CertificateField, CertificateRule};
+
+fn open(path: &str) -> anyhow::Result<BufReader<File>> {
+    Ok(BufReader::new(File::open(path).with_context(|| format!("Cannot open {}", path))?))
+}
+
+/// Builds the configuration of the HTTPS server.
+pub fn server_config(tls: &TlsConfig) -> anyhow::Result<ServerConfig> {
+    let verifier = match tls.client_ca {
+        Some(ref path) => {
+            let mut roots = RootCertStore::empty();
+            match roots.add_pem_file(&mut open(path)?) {
+                Ok((0, _)) | Err(()) => bail!("{} doesn't contain any valid certificates", path),
+                Ok(_) => {}
+            }
+            if tls.require_client_certificate {
+                AllowAnyAuthenticatedClient::new(roots)
+            } else {
+                AllowAnyAnonymousOrAuthenticatedClient::new(roots)
+            }
+        },
+        None => NoClientAuth::new()
+    };
+    let mut config = ServerConfig::new(verifier);
+
+    let certificates = pemfile::certs(&mut open(&tls.certificate)?).map_err(|_| anyhow!("Cannot parse {}", tls.certificate))?;
+    if certificates.is_empty() {
+        bail!("{} doesn't contain any certificates", tls.certificate);
+    }
+    let mut keys = pemfile::pkcs8_private_keys(&mut open(&tls.key)?).map_err(|_| anyhow!("Cannot parse {}", tls.key))?;
+    if keys.is_empty() {
+        keys = pemfile::rsa_private_keys(&mut open(&tls.key)?).map_err(|_| anyhow!("Cannot parse {}", tls.key))?;
+    }
+    let key = keys.into_iter().next().ok_or_else(|| anyhow!("{} doesn't contain a private key", tls.key))?;
+    config.set_single_cert(certificates, key).with_context(|| format!("Cannot use the key in {}", tls.key))?;
+    Ok(config)
+}
+
+/// The names in the certificate a client has connected with, which has been verified by then.
+#[derive(Debug, Clone, Default)]
+pub struct ClientCertificate {
+    pub names: Vec<(CertificateField, String)>,
+}
+
+impl ClientCertificate {
+    /// Returns the login of the first name a rule applies to.
+    pub fn login(&self, rules: &[CertificateRule]) -> Option<String> {
+        rules.iter().find_map(|rule| self.names.iter().find_map(|(field, name)| rule.login(*field, name)))
+    }
+}
+
+/// Reads the names of the client's certificate, when a connection is made.
+pub fn client_certificate(stream: &TlsStream<TcpStream>) -> ClientCertificate {
+    let mut names = vec![];
+    let certificates = stream.get_ref().1.get_peer_certificates().unwrap_or_default();
+    // The client's own certificate comes first, followed by the ones that issued it
+    let certificate = match certificates.first().map(|der| x509_parser::parse_x509_certificate(&der.0)) {
+        Some(Ok((_, certificate))) => certificate,
+        _ => return ClientCertificate { names }
+    };
+    for name in certificate.subject().iter_common_name().filter_map(|cn| cn.as_str().ok()) {
+        names.push((CertificateField::CommonName, name.to_owned()));
+    }
+    if let Some((_, alternative)) = certificate.tbs_certificate.subject_alternative_name() {
+        for name in &alternative.general_names {
+            match name {
+                GeneralName::DNSName(name) => names.push((CertificateField::DnsName, name.to_string())),
+                GeneralName::RFC822Name(name) => names.push((CertificateField::Email, name.to_string())),
+                GeneralName::URI(name) => names.push((CertificateField::Uri, name.to_string())),
+                _ => {}
+            }
+        }
+    }
+    ClientCertificate { names }
+}
+
+/// Marks a request as made over HTTPS.
+///
+/// actix only knows that of servers it sets up itself, and otherwise takes the scheme from the request's URI.
+/// Over HTTP/1.1, that's just a path, so the scheme and the `Host` are put in front of it.
+pub fn mark_secure(mut req: Request) -> Request {
+    let mut parts = req.head().uri.clone().into_parts();
+    if parts.scheme.is_some() || !req.path().starts_with('/') {
+        return req
+    }
+    let authority = match req.head().headers.get(header::HOST).and_then(|host| host.to_str().ok()?.parse().ok()) {
+        Some(authority) => authority,
+        None => return req
+    };
+    parts.scheme = Some(Scheme::HTTPS);
+    parts.authority = Some(authority);
+    if let Ok(uri) = Uri::from_parts(parts) {
+        req.head_mut().uri = uri;
+    }
+    req
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mark_secure;
+    use actix_http::test::TestRequest;
+    use actix_web::dev::{AppConfig, ConnectionInfo};
+    use http::header;
+
+    #[test]
+    fn requests_are_https() {
+        let req = mark_secure(TestRequest::with_uri("/auth/callback?code=1").header(header::HOST, "taru.example:8443").finish());
+        let info = ConnectionInfo::get(req.head(), &AppConfig::default());
+        assert_eq!((info.scheme(), info.host()), ("https", "taru.example:8443"));
+        assert_eq!(req.path(), "/auth/callback");
+        assert_eq!(req.uri().query(), Some("code=1"));
+    }
+}
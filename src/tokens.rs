@@ -0,0 +1,187 @@
+use actix_web::{web, get, post, delete, HttpRequest, HttpResponse};
+use parking_lot::RwLock;
+use serde::{Serialize, Deserialize};
+use chrono::{DateTime, Utc};
+use rand::{Rng, distributions::Alphanumeric};
+use ring::digest::{digest, SHA256};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+use anyhow::Context;
+
+use crate::app_state::AppState;
+use crate::auth::{self, Identity};
+use crate::cfg::{Config, TaskEntry, Token};
+use crate::audit::{self, Record, Outcome};
+
+/// Returns the hex-encoded SHA-256 hash of a token, which is all that's stored.
+pub fn hash(token: &str) -> String {
+    digest(&SHA256, token.as_bytes()).as_ref().iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Reads the tokens created with the API. A missing file just means that none have been.
+pub fn read(config: &Config) -> anyhow::Result<Vec<Token>> {
+    let path = match config.token_file {
+        Some(ref path) => path,
+        None => return Ok(vec![])
+    };
+    if !Path::new(path).exists() {
+        return Ok(vec![])
+    }
+    let file = std::fs::File::open(path).with_context(|| format!("Cannot open {}", path))?;
+    serde_yaml::from_reader(file).with_context(|| format!("Cannot parse {}", path))
+}
+
+/// Replaces the token file, so that it's never left half-written.
+fn write(path: &str, tokens: &[Token]) -> anyhow::Result<()> {
+    let dir = Path::new(path).parent().filter(|dir| !dir.as_os_str().is_empty()).unwrap_or_else(|| Path::new("."));
+    // Temporary files are only readable by their owner, which suits the hashes just fine
+    let mut file = tempfile::NamedTempFile::new_in(dir).with_context(|| format!("Cannot write {}", path))?;
+    serde_yaml::to_writer(&mut file, tokens).with_context(|| format!("Cannot write {}", path))?;
+    file.flush().with_context(|| format!("Cannot write {}", path))?;
+    file.persist(path).with_context(|| format!("Cannot write {}", path))?;
+    Ok(())
+}
+
+/// Returns the identity a token authenticates, unless it's unknown or expired.
+pub fn identity(state: &AppState, token: &str) -> Option<Identity> {
+    let hash = hash(token);
+    let token = state.config.tokens.iter().chain(state.tokens.iter()).find(|known| known.hash == hash)?;
+    if token.expires.map_or(false, |expires| expires <= Utc::now()) {
+        return None
+    }
+    // Tokens of users who have been removed from the configuration stop working
+    if !state.config.users.contains_key(&token.user) {
+        return None
+    }
+    let mut identity = Identity::new(token.user.clone(), vec![]);
+    identity.scope = token.tasks.clone();
+    identity.token = Some(token.name.clone());
+    Some(identity)
+}
+
+/// A token as shown to its owner, without the hash.
+#[derive(Serialize)]
+struct TokenInfo<'a> {
+    name: &'a str,
+    expires: Option<DateTime<Utc>>,
+    tasks: &'a Option<Vec<TaskEntry>>,
+    /// Tokens from the configuration can only be removed by changing it.
+    revocable: bool,
+}
+
+/// Tokens can only be managed by someone who has logged in, not with another token.
+fn owner(req: &HttpRequest) -> Result<Identity, HttpResponse> {
+    match auth::identity(req) {
+        Some(identity) if identity.anonymous => Err(HttpResponse::Unauthorized().body("Please log in.")),
+        Some(identity) if identity.token.is_none() => Ok(identity),
+        Some(_) => Err(HttpResponse::Forbidden().body("Tokens can't be managed using a token.")),
+        None => Err(HttpResponse::NotFound().body("Tokens are only available when authentication is enabled."))
+    }
+}
+
+#[get("/tokens")]
+async fn token_list(req: HttpRequest, data: web::Data<Arc<RwLock<AppState>>>) -> HttpResponse {
+    let owner = match owner(&req) {
+        Ok(owner) => owner,
+        Err(response) => return response
+    };
+    let data = data.read();
+    let configured = data.config.tokens.iter().map(|token| (token, false));
+    let created = data.tokens.iter().map(|token| (token, true));
+    HttpResponse::Ok().json(
+        configured.chain(created)
+            .filter(|(token, _)| token.user == owner.login)
+            .map(|(token, revocable)| TokenInfo { name: &token.name, expires: token.expires, tasks: &token.tasks, revocable })
+            .collect::<Vec<_>>()
+    )
+}
+
+#[derive(Deserialize)]
+pub struct NewToken {
+    name: String,
+    expires: Option<DateTime<Utc>>,
+    tasks: Option<Vec<TaskEntry>>,
+}
+
+#[post("/tokens")]
+async fn token_create(req: HttpRequest, data: web::Data<Arc<RwLock<AppState>>>, body: web::Json<NewToken>) -> HttpResponse {
+    let record = Record::request("token_create", Outcome::Success, &req).detail(body.name.clone());
+    let response = create(&req, &data, body.into_inner());
+    audit::log(&req, record.status(response.status()));
+    response
+}
+
+fn create(req: &HttpRequest, data: &web::Data<Arc<RwLock<AppState>>>, body: NewToken) -> HttpResponse {
+    let owner = match owner(req) {
+        Ok(owner) => owner,
+        Err(response) => return response
+    };
+    if body.name.is_empty() {
+        return HttpResponse::BadRequest().body("A token has to have a name.")
+    }
+    if body.expires.map_or(false, |expires| expires <= Utc::now()) {
+        return HttpResponse::BadRequest().body("The token would have already expired.")
+    }
+    if !data.read().config.users.contains_key(&owner.login) {
+        return HttpResponse::Forbidden().body("Only configured users can create tokens.")
+    }
+
+    let secret = format!("taru_{}", rand::thread_rng().sample_iter(&Alphanumeric).take(40).collect::<String>());
+    let token = Token { name: body.name, hash: hash(&secret), user: owner.login, expires: body.expires, tasks: body.tasks };
+
+    let mut data = data.write();
+    let path = match data.config.token_file.clone() {
+        Some(path) => path,
+        None => return HttpResponse::NotFound().body("Tokens can't be created, because there's no token_file configured.")
+    };
+    let taken = data.config.tokens.iter().chain(data.tokens.iter()).any(|known| known.user == token.user && known.name == token.name);
+    if taken {
+        return HttpResponse::Conflict().body(format!("You already have a token called {}.", token.name))
+    }
+    let mut tokens = data.tokens.clone();
+    tokens.push(token.clone());
+    if let Err(e) = write(&path, &tokens) {
+        return HttpResponse::InternalServerError().body(format!("{:#}", e))
+    }
+    data.tokens = tokens;
+
+    // This is the only time the token itself is shown
+    HttpResponse::Created().json(serde_json::json!({
+        "name": token.name,
+        "token": secret,
+        "expires": token.expires,
+        "tasks": token.tasks,
+    }))
+}
+
+#[delete("/tokens/{name}")]
+async fn token_revoke(req: HttpRequest, data: web::Data<Arc<RwLock<AppState>>>, params: web::Path<(String,)>) -> HttpResponse {
+    let record = Record::request("token_revoke", Outcome::Success, &req).detail(params.0.clone());
+    let response = revoke(&req, &data, &params.0);
+    audit::log(&req, record.status(response.status()));
+    response
+}
+
+fn revoke(req: &HttpRequest, data: &web::Data<Arc<RwLock<AppState>>>, name: &str) -> HttpResponse {
+    let owner = match owner(req) {
+        Ok(owner) => owner,
+        Err(response) => return response
+    };
+    let mut data = data.write();
+    let is_owned = |token: &Token| token.user == owner.login && token.name == name;
+    if data.config.tokens.iter().any(is_owned) {
+        return HttpResponse::Conflict().body("This token is defined in the configuration, and can only be removed from there.")
+    }
+    if !data.tokens.iter().any(is_owned) {
+        return HttpResponse::NotFound().finish()
+    }
+    let tokens: Vec<Token> = data.tokens.iter().filter(|token| !is_owned(token)).cloned().collect();
+    // There can't be any created tokens without a token file
+    let path = data.config.token_file.clone().unwrap_or_default();
+    if let Err(e) = write(&path, &tokens) {
+        return HttpResponse::InternalServerError().body(format!("{:#}", e))
+    }
+    data.tokens = tokens;
+    HttpResponse::Ok().body("Ok")
+}
@@ -0,0 +1,174 @@
+//! Logs in through a mock OpenID Connect provider, the way a browser would.
+
+use std::io::Write;
+use std::net::{TcpListener, TcpStream};
+use std::os::unix::io::AsRawFd;
+use std::os::unix::process::CommandExt;
+use std::process::{Child, Command, Stdio};
+use std::time::Duration;
+use actix_web::{web, App, HttpRequest, HttpResponse, HttpServer};
+use actix_web::client::{Client, ClientResponse};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+const CODE: &str = "the-code";
+const ACCESS_TOKEN: &str = "the-access-token";
+
+async fn discovery(req: HttpRequest) -> HttpResponse {
+    let issuer = format!("http://{}", req.connection_info().host());
+    HttpResponse::Ok().json(json!({
+        "issuer": issuer,
+        "authorization_endpoint": format!("{}/authorize", issuer),
+        "token_endpoint": format!("{}/token", issuer),
+        "userinfo_endpoint": format!("{}/userinfo", issuer),
+    }))
+}
+
+#[derive(Deserialize)]
+struct TokenRequest {
+    grant_type: String,
+    code: String,
+    client_id: String,
+    client_secret: String,
+}
+
+async fn token(form: web::Form<TokenRequest>) -> HttpResponse {
+    if form.grant_type != "authorization_code" || form.code != CODE || form.client_id != "taru" || form.client_secret != "hunter2" {
+        return HttpResponse::BadRequest().json(json!({ "error": "invalid_grant" }))
+    }
+    HttpResponse::Ok().json(json!({ "access_token": ACCESS_TOKEN, "token_type": "Bearer" }))
+}
+
+async fn userinfo(req: HttpRequest) -> HttpResponse {
+    let authorization = req.headers().get("authorization").and_then(|h| h.to_str().ok());
+    if authorization != Some(&format!("Bearer {}", ACCESS_TOKEN)) {
+        return HttpResponse::Unauthorized().finish()
+    }
+    HttpResponse::Ok().json(json!({ "sub": "1", "preferred_username": "alice", "groups": ["ops"] }))
+}
+
+/// Taru, listening on a socket passed to it the way systemd does.
+struct Taru {
+    child: Child,
+    url: String,
+    _dir: tempfile::TempDir,
+}
+
+impl Taru {
+    fn start(config: &str) -> Taru {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("taru.yml");
+        std::fs::File::create(&path).unwrap().write_all(config.as_bytes()).unwrap();
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let fd = listener.as_raw_fd();
+        let mut command = Command::new(env!("CARGO_BIN_EXE_taru"));
+        command.arg(&path).env("LISTEN_FDS", "1").env_remove("LISTEN_PID").stdout(Stdio::null());
+        unsafe {
+            command.pre_exec(move || {
+                // dup2 clears close-on-exec, unless the descriptor is already the one systemd would pass
+                let result = if fd == 3 { libc::fcntl(3, libc::F_SETFD, 0) } else { libc::dup2(fd, 3) };
+                if result == -1 {
+                    return Err(std::io::Error::last_os_error())
+                }
+                Ok(())
+            });
+        }
+        let child = command.spawn().unwrap();
+        drop(listener);
+
+        let address = url.trim_start_matches("http://").to_owned();
+        for _ in 0..100 {
+            if TcpStream::connect(&address).is_ok() {
+                break
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        Taru { child, url, _dir: dir }
+    }
+}
+
+impl Drop for Taru {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+fn header<S>(response: &ClientResponse<S>, name: &str) -> String {
+    response.headers().get(name).unwrap_or_else(|| panic!("No {} header", name)).to_str().unwrap().to_owned()
+}
+
+/// The `name=value` part of the session cookie a response sets.
+fn session_cookie<S>(response: &ClientResponse<S>) -> String {
+    header(response, "set-cookie").split(';').next().unwrap().to_owned()
+}
+
+#[actix_rt::test]
+async fn login_with_oidc() {
+    let provider = HttpServer::new(|| App::new()
+        .route("/.well-known/openid-configuration", web::get().to(discovery))
+        .route("/token", web::post().to(token))
+        .route("/userinfo", web::get().to(userinfo))
+    ).bind("127.0.0.1:0").unwrap();
+    let issuer = format!("http://{}", provider.addrs()[0]);
+    let provider = provider.run();
+
+    let taru = Taru::start(&format!(r#"
+tasks:
+  hello:
+    command: [echo, hello]
+  secret:
+    command: [echo, secret]
+groups:
+  ops:
+    can_view_status: [hello]
+auth:
+  oidc:
+    issuer: {}
+    client_id: taru
+    client_secret: hunter2
+"#, issuer));
+    let client = Client::default();
+
+    let response = client.get(format!("{}/api/v1/tasks", taru.url)).send().await.unwrap();
+    assert_eq!(response.status(), 401);
+
+    // Taru sends the browser to the provider, remembering where it's supposed to come back
+    let response = client.get(format!("{}/auth/login?return=/", taru.url)).send().await.unwrap();
+    assert_eq!(response.status(), 302);
+    let location = url::Url::parse(&header(&response, "location")).unwrap();
+    assert_eq!(location.as_str().split('?').next().unwrap(), format!("{}/authorize", issuer));
+    let query: std::collections::HashMap<_, _> = location.query_pairs().into_owned().collect();
+    assert_eq!(query["client_id"], "taru");
+    assert_eq!(query["redirect_uri"], format!("{}/auth/callback", taru.url));
+    let cookie = session_cookie(&response);
+
+    // A callback that doesn't come from this login is refused
+    let response = client.get(format!("{}/auth/callback?code={}&state=forged", taru.url, CODE))
+        .header("cookie", cookie.clone()).send().await.unwrap();
+    assert_eq!(response.status(), 400);
+
+    let response = client.get(format!("{}/auth/callback?code={}&state={}", taru.url, CODE, query["state"]))
+        .header("cookie", cookie).send().await.unwrap();
+    assert_eq!(response.status(), 302);
+    assert_eq!(header(&response, "location"), "/");
+    let cookie = session_cookie(&response);
+
+    let mut response = client.get(format!("{}/auth/session", taru.url)).header("cookie", cookie.clone()).send().await.unwrap();
+    assert_eq!(response.status(), 200);
+    let session: Value = response.json().await.unwrap();
+    assert_eq!(session["login"], "alice");
+    assert_eq!(session["groups"], json!(["ops"]));
+
+    // The groups from the provider give the user their permissions
+    let mut response = client.get(format!("{}/api/v1/tasks", taru.url)).header("cookie", cookie).send().await.unwrap();
+    assert_eq!(response.status(), 200);
+    let tasks: Value = response.json().await.unwrap();
+    let tasks = tasks.as_object().unwrap();
+    assert!(tasks.contains_key("hello"));
+    assert!(!tasks.contains_key("secret"));
+
+    provider.stop(false).await;
+}